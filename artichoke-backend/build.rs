@@ -220,6 +220,9 @@ mod libmruby {
             .include(ext_include_dir())
             .define("MRB_DISABLE_STDIO", None)
             .define("MRB_UTF8_STRING", None)
+            // Enable the VM code fetch hook, which backs the instruction
+            // limit mechanism in `Artichoke::set_instruction_limit`.
+            .define("MRB_ENABLE_DEBUG_HOOK", None)
             .define(mrb_int, None)
             .define("DISABLE_GEMS", None);
 
@@ -261,6 +264,7 @@ mod libmruby {
             .clang_arg(format!("-I{}", ext_include_dir().to_str().unwrap()))
             .clang_arg("-DMRB_DISABLE_STDIO")
             .clang_arg("-DMRB_UTF8_STRING")
+            .clang_arg("-DMRB_ENABLE_DEBUG_HOOK")
             .clang_arg(format!("-D{}", mrb_int))
             .whitelist_function("^mrb.*")
             .whitelist_type("^mrb.*")
@@ -141,6 +141,11 @@ MRB_API _Bool mrb_sys_gc_disable(mrb_state *mrb);
  */
 MRB_API _Bool mrb_sys_gc_enable(mrb_state *mrb);
 
+/**
+ * Check whether GC is enabled without modifying its state.
+ */
+MRB_API _Bool mrb_sys_gc_is_enabled(mrb_state *mrb);
+
 MRB_API _Bool mrb_sys_value_is_dead(mrb_state *_mrb, mrb_value value);
 
 MRB_API int mrb_sys_gc_live_objects(mrb_state *mrb);
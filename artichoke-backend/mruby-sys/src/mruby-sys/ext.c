@@ -222,6 +222,10 @@ MRB_API _Bool mrb_sys_gc_enable(mrb_state *mrb) {
   return was_enabled;
 }
 
+MRB_API _Bool mrb_sys_gc_is_enabled(mrb_state *mrb) {
+  return !mrb->gc.disabled;
+}
+
 MRB_API _Bool mrb_sys_value_is_dead(mrb_state *mrb, mrb_value value) {
   // immediate values such as Fixnums and Symbols are never garbage
   // collected, so they are never dead. See `mrb_gc_protect` in gc.c.
@@ -1,15 +1,20 @@
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::collections::HashSet;
 use std::convert::TryFrom;
 use std::ffi::{CStr, CString};
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::rc::Rc;
 
-use crate::def::{EnclosingRubyScope, Free, Method};
+use crate::convert::RustBackedValue;
+use crate::def::{self, EnclosingRubyScope, Free, Method};
+use crate::extn::core::exception::{RubyException, TypeError};
 use crate::method;
+use crate::module;
 use crate::sys;
 use crate::types::Int;
-use crate::value::Value;
+use crate::value::{Value, ValueLike};
 use crate::{Artichoke, ArtichokeError};
 
 #[derive(Clone)]
@@ -18,9 +23,63 @@ pub struct Builder<'a> {
     spec: &'a Spec,
     is_mrb_tt_data: bool,
     super_class: Option<&'a Spec>,
+    included_modules: Vec<&'a module::Spec>,
+    prepended_modules: Vec<&'a module::Spec>,
+    extended_modules: Vec<&'a module::Spec>,
     methods: HashSet<method::Spec>,
 }
 
+/// Extract the Rust object backing an [`MRB_TT_DATA`] value.
+///
+/// Returns [`None`] if the value is not a data object, is backed by a
+/// different Rust type, or was allocated without calling `initialize`.
+///
+/// The object is returned as the [`Rc`]`<`[`RefCell`]`<T>>` that
+/// [`RustBackedValue`] stores in the data slot rather than a bare `&mut T`.
+/// The mruby GC owns the data slot, so handing out an unguarded reference
+/// would alias the pointer that [`def::rust_data_free`] consumes.
+///
+/// [`MRB_TT_DATA`]: sys::mrb_vtype::MRB_TT_DATA
+pub fn get_data<T>(interp: &Artichoke, value: &Value) -> Option<Rc<RefCell<T>>>
+where
+    T: RustBackedValue,
+{
+    unsafe { T::try_from_ruby(interp, value) }.ok()
+}
+
+/// Extract the Rust object backing an [`MRB_TT_DATA`] value, raising
+/// `TypeError` on mismatch.
+///
+/// This is the checked companion to [`get_data`] for use in Rust-backed
+/// methods: extraction failures surface to Ruby as a `TypeError` instead of
+/// requiring each call site to hand-roll an error mapping around
+/// [`RustBackedValue::try_from_ruby`].
+///
+/// [`MRB_TT_DATA`]: sys::mrb_vtype::MRB_TT_DATA
+pub fn try_get_data<T>(
+    interp: &Artichoke,
+    value: &Value,
+) -> Result<Rc<RefCell<T>>, Box<dyn RubyException>>
+where
+    T: RustBackedValue,
+{
+    match unsafe { T::try_from_ruby(interp, value) } {
+        Ok(data) => Ok(data),
+        Err(ArtichokeError::UninitializedValue(class)) => Err(Box::new(TypeError::new(
+            interp,
+            format!("uninitialized {}", class),
+        ))),
+        Err(_) => Err(Box::new(TypeError::new(
+            interp,
+            format!(
+                "wrong argument type {} (expected {})",
+                value.pretty_name(),
+                T::ruby_type_name()
+            ),
+        ))),
+    }
+}
+
 impl<'a> Builder<'a> {
     pub fn for_spec(interp: &'a Artichoke, spec: &'a Spec) -> Self {
         Self {
@@ -28,6 +87,9 @@ impl<'a> Builder<'a> {
             spec,
             is_mrb_tt_data: false,
             super_class: None,
+            included_modules: Vec::default(),
+            prepended_modules: Vec::default(),
+            extended_modules: Vec::default(),
             methods: HashSet::default(),
         }
     }
@@ -42,18 +104,113 @@ impl<'a> Builder<'a> {
         self
     }
 
+    pub fn include_module(mut self, module: &'a module::Spec) -> Self {
+        self.included_modules.push(module);
+        self
+    }
+
+    /// Prepend a module to the class being defined.
+    ///
+    /// Prepended modules are inserted before the class in the method lookup
+    /// chain, so a method defined in the module wraps the class's
+    /// implementation and can invoke it with `super`. This matches Ruby's
+    /// `Module#prepend`.
+    pub fn prepend_module(mut self, module: &'a module::Spec) -> Self {
+        self.prepended_modules.push(module);
+        self
+    }
+
+    /// Extend the class being defined with a module.
+    ///
+    /// The module's instance methods become class methods, as if the class
+    /// body called `extend SomeModule`. Compare with
+    /// [`Builder::include_module`], which makes module instance methods
+    /// available on instances of the class.
+    pub fn extend_module(mut self, module: &'a module::Spec) -> Self {
+        self.extended_modules.push(module);
+        self
+    }
+
+    /// Add an instance method to the class being defined.
+    ///
+    /// Methods registered here are backed by Rust functions. Implementations
+    /// that perform long allocating operations should hold a
+    /// [`GcArenaGuard`](crate::state::GcArenaGuard) for the duration of the
+    /// operation so intermediate `mrb_value`s do not pin the GC arena.
     pub fn add_method(mut self, name: &str, method: Method, args: sys::mrb_aspec) -> Self {
         let spec = method::Spec::new(method::Type::Instance, name, method, args);
         self.methods.insert(spec);
         self
     }
 
-    pub fn add_self_method(mut self, name: &str, method: Method, args: sys::mrb_aspec) -> Self {
+    /// Add a class method to the class being defined.
+    ///
+    /// Class methods are called "self methods" in some older parts of the
+    /// codebase. They are defined with
+    /// [`sys::mrb_define_class_method`](crate::sys::mrb_define_class_method).
+    pub fn add_class_method(mut self, name: &str, method: Method, args: sys::mrb_aspec) -> Self {
         let spec = method::Spec::new(method::Type::Class, name, method, args);
         self.methods.insert(spec);
         self
     }
 
+    #[deprecated(note = "use `add_class_method`, which matches Ruby and mruby terminology")]
+    pub fn add_self_method(self, name: &str, method: Method, args: sys::mrb_aspec) -> Self {
+        self.add_class_method(name, method, args)
+    }
+
+    /// Register a Rust-native `initialize` method for a class whose instances
+    /// are backed by a Rust object.
+    ///
+    /// The generated `initialize` forwards its arguments to
+    /// [`def::DataConstructor::construct`] and stores the constructed object
+    /// in the receiver's data slot via the
+    /// [`mrb_data_type`](sys::mrb_data_type) generated by [`Spec::new`]. The
+    /// class is marked [`MRB_TT_DATA`](sys::mrb_vtype::MRB_TT_DATA) as if
+    /// [`Builder::value_is_rust_object`] had been called.
+    ///
+    /// The [`Spec`] should be created with a free function of
+    /// [`def::rust_data_free`] for `T` so the VM can reclaim the constructed
+    /// object.
+    pub fn define_data_constructor<T>(self) -> Self
+    where
+        T: def::DataConstructor,
+    {
+        self.value_is_rust_object().add_method(
+            "initialize",
+            def::rust_data_construct::<T>,
+            sys::mrb_args_any(),
+        )
+    }
+
+    /// Define a method on the singleton class of `obj`.
+    ///
+    /// Unlike [`Builder::add_method`] and [`Builder::add_class_method`],
+    /// which are batched until [`Builder::define`], the singleton method is
+    /// defined immediately and is only visible on `obj` itself. This is
+    /// useful for decorating well-known instances like `$stdout`.
+    pub fn define_singleton_method(
+        &self,
+        obj: Value,
+        name: &str,
+        method: Method,
+        args: sys::mrb_aspec,
+    ) -> Result<(), ArtichokeError> {
+        let mrb = self.interp.0.borrow().mrb;
+        let spec = method::Spec::new(method::Type::Class, name, method, args);
+        unsafe {
+            let obj = sys::mrb_sys_obj_ptr(obj.inner()) as *mut sys::RObject;
+            sys::mrb_define_singleton_method(
+                mrb,
+                obj,
+                spec.cstring().as_ptr(),
+                Some(spec.method()),
+                args,
+            );
+        }
+        Ok(())
+    }
+
     pub fn define(self) -> Result<(), ArtichokeError> {
         let mrb = self.interp.0.borrow().mrb;
         let super_class = if let Some(spec) = self.super_class {
@@ -79,10 +236,37 @@ impl<'a> Builder<'a> {
         } else {
             unsafe { sys::mrb_define_class(mrb, self.spec.name_c_str().as_ptr(), super_class) }
         };
+        for module in &self.included_modules {
+            let module = module.rclass(self.interp).ok_or_else(|| {
+                ArtichokeError::NotDefined(Cow::Owned(module.fqname().into_owned()))
+            })?;
+            unsafe {
+                sys::mrb_include_module(mrb, rclass, module);
+            }
+        }
+        for module in &self.prepended_modules {
+            let module = module.rclass(self.interp).ok_or_else(|| {
+                ArtichokeError::NotDefined(Cow::Owned(module.fqname().into_owned()))
+            })?;
+            unsafe {
+                sys::mrb_prepend_module(mrb, rclass, module);
+            }
+        }
+        for module in &self.extended_modules {
+            let module = module.rclass(self.interp).ok_or_else(|| {
+                ArtichokeError::NotDefined(Cow::Owned(module.fqname().into_owned()))
+            })?;
+            // mruby does not expose an `mrb_extend_module`, so route through
+            // `Object#extend` on the class object.
+            let class = Value::new(self.interp, unsafe { sys::mrb_sys_class_value(rclass) });
+            let module = Value::new(self.interp, unsafe { sys::mrb_sys_module_value(module) });
+            ValueLike::funcall::<Value>(&class, "extend", &[module], None)?;
+        }
         for method in &self.methods {
             unsafe {
                 method.define(self.interp, rclass)?;
             }
+            self.spec.record_method(method.name(), *method.method_type());
         }
         // If a `Spec` defines a `Class` whose isntances own a pointer to a
         // Rust object, mark them as `MRB_TT_DATA`.
@@ -101,6 +285,7 @@ pub struct Spec {
     cstring: CString,
     data_type: sys::mrb_data_type,
     enclosing_scope: Option<Box<EnclosingRubyScope>>,
+    methods: RefCell<Vec<(String, method::Type)>>,
 }
 
 impl Spec {
@@ -119,6 +304,30 @@ impl Spec {
             cstring,
             data_type,
             enclosing_scope: enclosing_scope.map(Box::new),
+            methods: RefCell::new(vec![]),
+        }
+    }
+
+    /// Names of all methods registered on this class via
+    /// [`Builder::add_method`] and [`Builder::add_class_method`].
+    ///
+    /// Methods are recorded when [`Builder::define`] registers them with the
+    /// interpreter. The returned names are unordered.
+    pub fn method_names(&self) -> Vec<String> {
+        self.methods
+            .borrow()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    fn record_method(&self, name: &str, method_type: method::Type) {
+        let mut methods = self.methods.borrow_mut();
+        let already_recorded = methods
+            .iter()
+            .any(|(existing, tpe)| existing == name && *tpe == method_type);
+        if !already_recorded {
+            methods.push((String::from(name), method_type));
         }
     }
 
@@ -238,6 +447,293 @@ mod tests {
     use crate::extn::core::exception::StandardError;
     use crate::extn::core::kernel::Kernel;
     use crate::module;
+    use crate::sys;
+
+    #[test]
+    fn method_names() {
+        struct Instrumented;
+
+        unsafe extern "C" fn noop(
+            _mrb: *mut sys::mrb_state,
+            _slf: sys::mrb_value,
+        ) -> sys::mrb_value {
+            sys::mrb_sys_nil_value()
+        }
+
+        let interp = crate::interpreter().expect("init");
+        let spec = class::Spec::new("Instrumented", None, None);
+        class::Builder::for_spec(&interp, &spec)
+            .add_method("foo", noop, sys::mrb_args_none())
+            .add_method("bar", noop, sys::mrb_args_none())
+            .add_class_method("baz", noop, sys::mrb_args_none())
+            .define()
+            .unwrap();
+        interp.0.borrow_mut().def_class::<Instrumented>(spec);
+        let borrow = interp.0.borrow();
+        let spec = borrow.class_spec::<Instrumented>().unwrap();
+        let mut names = spec.method_names();
+        names.sort();
+        assert_eq!(names, vec!["bar", "baz", "foo"]);
+    }
+
+    #[test]
+    fn add_class_method_defines_class_method() {
+        struct Classy;
+
+        unsafe extern "C" fn seven(
+            _mrb: *mut sys::mrb_state,
+            _slf: sys::mrb_value,
+        ) -> sys::mrb_value {
+            sys::mrb_sys_fixnum_value(7)
+        }
+
+        let interp = crate::interpreter().expect("init");
+        let spec = class::Spec::new("Classy", None, None);
+        class::Builder::for_spec(&interp, &spec)
+            .add_class_method("seven", seven, sys::mrb_args_none())
+            .define()
+            .unwrap();
+        interp.0.borrow_mut().def_class::<Classy>(spec);
+        let result = interp.eval(b"Classy.seven").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 7);
+        // Class methods are not visible on instances.
+        let result = interp.eval(b"Classy.new.respond_to?(:seven)").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn add_self_method_is_a_deprecated_alias() {
+        struct Legacy;
+
+        unsafe extern "C" fn noop(
+            _mrb: *mut sys::mrb_state,
+            _slf: sys::mrb_value,
+        ) -> sys::mrb_value {
+            sys::mrb_sys_nil_value()
+        }
+
+        let interp = crate::interpreter().expect("init");
+        let spec = class::Spec::new("Legacy", None, None);
+        class::Builder::for_spec(&interp, &spec)
+            .add_self_method("noop", noop, sys::mrb_args_none())
+            .define()
+            .unwrap();
+        interp.0.borrow_mut().def_class::<Legacy>(spec);
+        let result = interp.eval(b"Legacy.respond_to?(:noop)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn define_data_constructor_stores_rust_object() {
+        use crate::convert::RustBackedValue;
+        use crate::def::{self, DataConstructor};
+        use crate::extn::core::exception::{ArgumentError, RubyException};
+        use crate::types::Int;
+        use crate::value::Value;
+        use crate::Artichoke;
+
+        #[derive(Debug, Clone)]
+        struct Counter {
+            count: Int,
+        }
+
+        impl RustBackedValue for Counter {
+            fn ruby_type_name() -> &'static str {
+                "Counter"
+            }
+        }
+
+        impl DataConstructor for Counter {
+            fn construct(
+                interp: &Artichoke,
+                args: &[Value],
+            ) -> Result<Self, Box<dyn RubyException>> {
+                let count = args
+                    .first()
+                    .cloned()
+                    .and_then(|count| count.try_into::<Int>().ok())
+                    .ok_or_else(|| -> Box<dyn RubyException> {
+                        Box::new(ArgumentError::new(interp, "count must be an Integer"))
+                    })?;
+                Ok(Self { count })
+            }
+        }
+
+        let interp = crate::interpreter().expect("init");
+        let spec = class::Spec::new("Counter", None, Some(def::rust_data_free::<Counter>));
+        class::Builder::for_spec(&interp, &spec)
+            .define_data_constructor::<Counter>()
+            .define()
+            .unwrap();
+        interp.0.borrow_mut().def_class::<Counter>(spec);
+        let counter = interp.eval(b"Counter.new(17)").expect("eval");
+        let data = unsafe { Counter::try_from_ruby(&interp, &counter) }.expect("convert");
+        assert_eq!(data.borrow().count, 17);
+        let result = interp.eval(b"Counter.new('seventeen')").map(|_| ());
+        assert_eq!(
+            result,
+            Err(crate::ArtichokeError::Exec(
+                "ArgumentError: count must be an Integer".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn get_data_checks_backing_type() {
+        use crate::convert::{Convert, RustBackedValue};
+        use crate::def;
+        use crate::extn::core::exception::RubyException;
+
+        #[derive(Debug, Clone)]
+        struct Token {
+            id: i64,
+        }
+
+        impl RustBackedValue for Token {
+            fn ruby_type_name() -> &'static str {
+                "Token"
+            }
+        }
+
+        let interp = crate::interpreter().expect("init");
+        let spec = class::Spec::new("Token", None, Some(def::rust_data_free::<Token>));
+        class::Builder::for_spec(&interp, &spec)
+            .value_is_rust_object()
+            .define()
+            .unwrap();
+        interp.0.borrow_mut().def_class::<Token>(spec);
+        let token = unsafe { Token { id: 7 }.try_into_ruby(&interp, None) }.expect("convert");
+        let data = class::get_data::<Token>(&interp, &token).expect("backed by Token");
+        assert_eq!(data.borrow().id, 7);
+        let data = class::try_get_data::<Token>(&interp, &token).expect("backed by Token");
+        assert_eq!(data.borrow().id, 7);
+
+        let not_data = interp.convert("a string");
+        assert!(class::get_data::<Token>(&interp, &not_data).is_none());
+        let exception =
+            class::try_get_data::<Token>(&interp, &not_data).expect_err("extraction fails");
+        assert_eq!(
+            exception.message(),
+            &b"wrong argument type String (expected Token)"[..]
+        );
+    }
+
+    #[test]
+    fn define_singleton_method_is_per_object() {
+        struct Single;
+
+        unsafe extern "C" fn special(
+            _mrb: *mut sys::mrb_state,
+            _slf: sys::mrb_value,
+        ) -> sys::mrb_value {
+            sys::mrb_sys_fixnum_value(29)
+        }
+
+        let interp = crate::interpreter().expect("init");
+        let spec = class::Spec::new("Single", None, None);
+        let builder = class::Builder::for_spec(&interp, &spec);
+        builder.clone().define().unwrap();
+        interp.0.borrow_mut().def_class::<Single>(spec);
+        let decorated = interp.eval(b"$decorated = Single.new").expect("eval");
+        builder
+            .define_singleton_method(decorated, "special", special, sys::mrb_args_none())
+            .unwrap();
+        let result = interp.eval(b"$decorated.special").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 29);
+        // The singleton method is not visible on other instances.
+        let result = interp
+            .eval(b"Single.new.respond_to?(:special)")
+            .expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn prepended_module_wraps_class_method() {
+        struct Wrapped;
+
+        unsafe extern "C" fn greet(
+            mrb: *mut sys::mrb_state,
+            _slf: sys::mrb_value,
+        ) -> sys::mrb_value {
+            use crate::convert::Convert;
+            let interp = unwrap_interpreter!(mrb);
+            let greeting: crate::value::Value = interp.convert("native");
+            greeting.inner()
+        }
+
+        let interp = crate::interpreter().expect("init");
+        let loud = module::Spec::new("Loud", None);
+        module::Builder::for_spec(&interp, &loud).define().unwrap();
+        interp
+            .eval(b"module Loud; def greet; \"prepended \" + super; end; end")
+            .expect("eval");
+        let spec = class::Spec::new("Wrapped", None, None);
+        class::Builder::for_spec(&interp, &spec)
+            .add_method("greet", greet, sys::mrb_args_none())
+            .prepend_module(&loud)
+            .define()
+            .unwrap();
+        interp.0.borrow_mut().def_class::<Wrapped>(spec);
+        let result = interp.eval(b"Wrapped.new.greet").expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            "prepended native"
+        );
+        // The module sits before the class in the ancestry chain.
+        let result = interp
+            .eval(b"Wrapped.ancestors.index(Loud) < Wrapped.ancestors.index(Wrapped)")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn extended_module_methods_become_class_methods() {
+        struct Extended;
+
+        let interp = crate::interpreter().expect("init");
+        let greeter = module::Spec::new("Greeter", None);
+        module::Builder::for_spec(&interp, &greeter)
+            .define()
+            .unwrap();
+        interp
+            .eval(b"module Greeter; def greet; 'hello'; end; end")
+            .expect("eval");
+        let spec = class::Spec::new("Extended", None, None);
+        class::Builder::for_spec(&interp, &spec)
+            .extend_module(&greeter)
+            .define()
+            .unwrap();
+        interp.0.borrow_mut().def_class::<Extended>(spec);
+        let result = interp.eval(b"Extended.greet").expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "hello");
+        // `extend` does not make the module's methods available on instances.
+        let result = interp
+            .eval(b"Extended.new.respond_to?(:greet)")
+            .expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn extend_object_decorates_single_instance() {
+        let interp = crate::interpreter().expect("init");
+        let greeter = module::Spec::new("Greeter", None);
+        module::Builder::for_spec(&interp, &greeter)
+            .define()
+            .unwrap();
+        interp
+            .eval(b"module Greeter; def greet; 'hello'; end; end")
+            .expect("eval");
+        let obj = interp.eval(b"$extended = Object.new").expect("eval");
+        interp.extend_object(&obj, &greeter).expect("extend");
+        let result = interp.eval(b"$extended.greet").expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "hello");
+        // Other instances are unaffected.
+        let result = interp
+            .eval(b"Object.new.respond_to?(:greet)")
+            .expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+    }
 
     #[test]
     fn super_class() {
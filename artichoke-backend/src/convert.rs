@@ -1,5 +1,38 @@
 pub use artichoke_core::convert::{Convert, TryConvert};
 
+use crate::ArtichokeError;
+
+/// Fallible conversions that only succeed when the source value is exactly
+/// the requested type.
+///
+/// [`TryConvert`] applies Ruby coercion semantics where they exist — for
+/// example truthiness when extracting a [`bool`]. `StrictConvert` performs no
+/// coercion: the conversion fails unless the value's type tag matches the
+/// destination type. This lets extension methods distinguish the Ruby `if x`
+/// pattern from `x == true`.
+pub trait StrictConvert<T, U> {
+    /// Perform the strict conversion.
+    ///
+    /// Returns [`ArtichokeError::ConvertToRust`] if the value is not exactly
+    /// the requested type.
+    fn strict_convert(&self, value: T) -> Result<U, ArtichokeError>;
+}
+
+/// Fallible conversions that replace invalid data instead of failing on it.
+///
+/// [`TryConvert`] is strict about data validity — extracting a [`String`]
+/// fails if the underlying bytes are not valid UTF-8. `ConvertLossy` replaces
+/// invalid UTF-8 sequences with U+FFFD REPLACEMENT CHARACTER, like
+/// [`String::from_utf8_lossy`]. The conversion still fails if the source value
+/// has the wrong type tag.
+pub trait ConvertLossy<T, U> {
+    /// Perform the lossy conversion.
+    ///
+    /// Returns [`ArtichokeError::ConvertToRust`] if the value is not the
+    /// requested type.
+    fn convert_lossy(&self, value: T) -> Result<U, ArtichokeError>;
+}
+
 mod array;
 mod boolean;
 mod bytes;
@@ -8,6 +41,8 @@ mod float;
 mod hash;
 mod nilable;
 mod object;
+#[cfg(feature = "artichoke-serde")]
+mod serde;
 mod string;
 
 pub use self::array::*;
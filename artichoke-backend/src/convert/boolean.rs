@@ -1,4 +1,4 @@
-use crate::convert::{Convert, TryConvert};
+use crate::convert::{Convert, StrictConvert, TryConvert};
 use crate::sys;
 use crate::types::{Ruby, Rust};
 use crate::value::Value;
@@ -14,8 +14,27 @@ impl Convert<bool, Value> for Artichoke {
     }
 }
 
+/// Convert with Ruby truthiness semantics.
+///
+/// `nil` and `false` are falsey; every other value is truthy. This matches
+/// the behavior of `if value` in Ruby source and never fails. Use
+/// [`StrictConvert`] to only accept `true` and `false` values.
 impl TryConvert<Value, bool> for Artichoke {
     fn try_convert(&self, value: Value) -> Result<bool, ArtichokeError> {
+        let value = value.inner();
+        let falsey = unsafe {
+            sys::mrb_sys_value_is_nil(value) || sys::mrb_sys_value_is_false(value)
+        };
+        Ok(!falsey)
+    }
+}
+
+/// Convert only Ruby `true` and `false` to a [`bool`].
+///
+/// This matches the behavior of `value == true || value == false` in Ruby
+/// source. All other values fail to convert.
+impl StrictConvert<Value, bool> for Artichoke {
+    fn strict_convert(&self, value: Value) -> Result<bool, ArtichokeError> {
         match value.ruby_type() {
             Ruby::Bool => {
                 let value = value.inner();
@@ -44,23 +63,54 @@ mod tests {
     use artichoke_core::eval::Eval;
     use quickcheck_macros::quickcheck;
 
-    use crate::convert::Convert;
+    use crate::convert::{Convert, StrictConvert};
     use crate::sys;
     use crate::types::{Ruby, Rust};
     use crate::value::ValueLike;
     use crate::ArtichokeError;
 
     #[test]
-    fn fail_convert() {
+    fn truthiness() {
         let interp = crate::interpreter().expect("init");
-        // get a mrb_value that can't be converted to a primitive type.
+        // `nil` and `false` are the only falsey values. Everything else is
+        // truthy, including `0`, `''`, and `[]`.
+        let value = interp.eval(b"nil").expect("eval");
+        assert_eq!(value.try_into::<bool>(), Ok(false));
+        let value = interp.eval(b"false").expect("eval");
+        assert_eq!(value.try_into::<bool>(), Ok(false));
         let value = interp.eval(b"Object.new").expect("eval");
-        let expected = Err(ArtichokeError::ConvertToRust {
-            from: Ruby::Object,
-            to: Rust::Bool,
-        });
-        let result = value.try_into::<bool>();
-        assert_eq!(result, expected);
+        assert_eq!(value.try_into::<bool>(), Ok(true));
+        let value = interp.eval(b"0").expect("eval");
+        assert_eq!(value.try_into::<bool>(), Ok(true));
+        let value = interp.eval(b"''").expect("eval");
+        assert_eq!(value.try_into::<bool>(), Ok(true));
+    }
+
+    #[test]
+    fn strict_convert_only_accepts_bools() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"true").expect("eval");
+        assert_eq!(interp.strict_convert(value), Ok(true));
+        let value = interp.eval(b"false").expect("eval");
+        assert_eq!(interp.strict_convert(value), Ok(false));
+        let value = interp.eval(b"Object.new").expect("eval");
+        let result: Result<bool, ArtichokeError> = interp.strict_convert(value);
+        assert_eq!(
+            result,
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::Object,
+                to: Rust::Bool,
+            })
+        );
+        let value = interp.eval(b"nil").expect("eval");
+        let result: Result<bool, ArtichokeError> = interp.strict_convert(value);
+        assert_eq!(
+            result,
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::Nil,
+                to: Rust::Bool,
+            })
+        );
     }
 
     #[quickcheck]
@@ -95,14 +145,10 @@ mod tests {
     }
 
     #[quickcheck]
-    fn roundtrip_err(i: i64) -> bool {
+    fn fixnum_is_truthy(i: i64) -> bool {
         let interp = crate::interpreter().expect("init");
         let value = interp.convert(i);
-        let value = value.try_into::<bool>();
-        let expected = Err(ArtichokeError::ConvertToRust {
-            from: Ruby::Fixnum,
-            to: Rust::Bool,
-        });
-        value == expected
+        // All `Fixnum`s are truthy, including zero.
+        value.try_into::<bool>() == Ok(true)
     }
 }
@@ -109,12 +109,107 @@ impl TryConvert<Value, Int> for Artichoke {
     }
 }
 
+impl TryConvert<Value, i8> for Artichoke {
+    fn try_convert(&self, value: Value) -> Result<i8, ArtichokeError> {
+        let value: Int = self.try_convert(value)?;
+        i8::try_from(value).map_err(|_| ArtichokeError::ConvertToRust {
+            from: Ruby::Fixnum,
+            to: Rust::SignedInt,
+        })
+    }
+}
+
+impl TryConvert<Value, i16> for Artichoke {
+    fn try_convert(&self, value: Value) -> Result<i16, ArtichokeError> {
+        let value: Int = self.try_convert(value)?;
+        i16::try_from(value).map_err(|_| ArtichokeError::ConvertToRust {
+            from: Ruby::Fixnum,
+            to: Rust::SignedInt,
+        })
+    }
+}
+
+impl TryConvert<Value, i32> for Artichoke {
+    fn try_convert(&self, value: Value) -> Result<i32, ArtichokeError> {
+        let value: Int = self.try_convert(value)?;
+        i32::try_from(value).map_err(|_| ArtichokeError::ConvertToRust {
+            from: Ruby::Fixnum,
+            to: Rust::SignedInt,
+        })
+    }
+}
+
+impl TryConvert<Value, u8> for Artichoke {
+    fn try_convert(&self, value: Value) -> Result<u8, ArtichokeError> {
+        let type_tag = value.ruby_type();
+        let value: Int = self.try_convert(value).map_err(|_| {
+            ArtichokeError::ConvertToRust {
+                from: type_tag,
+                to: Rust::UnsignedInt,
+            }
+        })?;
+        u8::try_from(value).map_err(|_| ArtichokeError::ConvertToRust {
+            from: Ruby::Fixnum,
+            to: Rust::UnsignedInt,
+        })
+    }
+}
+
+impl TryConvert<Value, u16> for Artichoke {
+    fn try_convert(&self, value: Value) -> Result<u16, ArtichokeError> {
+        let type_tag = value.ruby_type();
+        let value: Int = self.try_convert(value).map_err(|_| {
+            ArtichokeError::ConvertToRust {
+                from: type_tag,
+                to: Rust::UnsignedInt,
+            }
+        })?;
+        u16::try_from(value).map_err(|_| ArtichokeError::ConvertToRust {
+            from: Ruby::Fixnum,
+            to: Rust::UnsignedInt,
+        })
+    }
+}
+
+impl TryConvert<Value, u32> for Artichoke {
+    fn try_convert(&self, value: Value) -> Result<u32, ArtichokeError> {
+        let type_tag = value.ruby_type();
+        let value: Int = self.try_convert(value).map_err(|_| {
+            ArtichokeError::ConvertToRust {
+                from: type_tag,
+                to: Rust::UnsignedInt,
+            }
+        })?;
+        u32::try_from(value).map_err(|_| ArtichokeError::ConvertToRust {
+            from: Ruby::Fixnum,
+            to: Rust::UnsignedInt,
+        })
+    }
+}
+
+impl TryConvert<Value, u64> for Artichoke {
+    fn try_convert(&self, value: Value) -> Result<u64, ArtichokeError> {
+        let type_tag = value.ruby_type();
+        let value: Int = self.try_convert(value).map_err(|_| {
+            ArtichokeError::ConvertToRust {
+                from: type_tag,
+                to: Rust::UnsignedInt,
+            }
+        })?;
+        u64::try_from(value).map_err(|_| ArtichokeError::ConvertToRust {
+            from: Ruby::Fixnum,
+            to: Rust::UnsignedInt,
+        })
+    }
+}
+
 impl TryConvert<Value, usize> for Artichoke {
     fn try_convert(&self, value: Value) -> Result<usize, ArtichokeError> {
+        let type_tag = value.ruby_type();
         let value: Int = self
             .try_convert(value)
             .map_err(|_| ArtichokeError::ConvertToRust {
-                from: Ruby::Fixnum,
+                from: type_tag,
                 to: Rust::UnsignedInt,
             })?;
         usize::try_from(value).map_err(|_| ArtichokeError::ConvertToRust {
@@ -184,6 +279,65 @@ mod tests {
         value == expected
     }
 
+    #[test]
+    fn fixnum_to_narrow_widths() {
+        let interp = crate::interpreter().expect("init");
+        let value: Value = interp.convert(100);
+        assert_eq!(value.clone().try_into::<i8>(), Ok(100));
+        assert_eq!(value.clone().try_into::<u8>(), Ok(100));
+        assert_eq!(value.clone().try_into::<i32>(), Ok(100));
+        assert_eq!(value.try_into::<u64>(), Ok(100));
+
+        // Out-of-range conversions fail with checked arithmetic instead of
+        // truncating.
+        let value: Value = interp.convert(Int::max_value());
+        assert_eq!(
+            value.try_into::<i32>(),
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::Fixnum,
+                to: Rust::SignedInt,
+            })
+        );
+        let value: Value = interp.convert(-1);
+        assert_eq!(
+            value.try_into::<u32>(),
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::Fixnum,
+                to: Rust::UnsignedInt,
+            })
+        );
+    }
+
+    #[test]
+    fn non_fixnum_to_narrow_widths_reports_actual_type() {
+        let interp = crate::interpreter().expect("init");
+        // Errors from converting a non-Fixnum report the receiver's type, not
+        // `Ruby::Fixnum`.
+        let value = interp.eval(b"'not a number'").expect("eval");
+        assert_eq!(
+            value.clone().try_into::<i32>(),
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::String,
+                to: Rust::SignedInt,
+            })
+        );
+        assert_eq!(
+            value.try_into::<u8>(),
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::String,
+                to: Rust::UnsignedInt,
+            })
+        );
+        let value: Value = interp.convert(None::<Value>);
+        assert_eq!(
+            value.try_into::<u32>(),
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::Nil,
+                to: Rust::UnsignedInt,
+            })
+        );
+    }
+
     #[test]
     fn fixnum_to_usize() {
         let interp = crate::interpreter().expect("init");
@@ -0,0 +1,149 @@
+//! Converters between [`Value`] and [`serde_json::Value`].
+//!
+//! Ruby `nil`, `true`, `false`, `Integer`, `Float`, `String`, `Array`, and
+//! `Hash` map to their JSON equivalents. `Symbol`s serialize as JSON strings.
+//! Non-serializable types like `Proc` and `Data` return a
+//! [`serde::ser::Error`].
+//!
+//! Deserializing arbitrary JSON into the interpreter requires an [`Artichoke`]
+//! to allocate values, so the deserialize direction is exposed as a
+//! [`Convert`] impl rather than a `serde::Deserialize` impl.
+
+use serde::ser::{Error, Serialize, SerializeMap, SerializeSeq, Serializer};
+
+use crate::convert::Convert;
+use crate::types::{Float, Int, Ruby};
+use crate::value::{Value, ValueLike};
+use crate::Artichoke;
+
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.ruby_type() {
+            Ruby::Nil => serializer.serialize_unit(),
+            Ruby::Bool => {
+                let value = self.clone().try_into::<bool>().map_err(Error::custom)?;
+                serializer.serialize_bool(value)
+            }
+            Ruby::Fixnum => {
+                let value = self.clone().try_into::<Int>().map_err(Error::custom)?;
+                serializer.serialize_i64(i64::from(value))
+            }
+            Ruby::Float => {
+                let value = self.clone().try_into::<Float>().map_err(Error::custom)?;
+                serializer.serialize_f64(value)
+            }
+            Ruby::String | Ruby::Symbol => {
+                let value = self.clone().try_into::<String>().map_err(Error::custom)?;
+                serializer.serialize_str(value.as_str())
+            }
+            Ruby::Array => {
+                let ary = self
+                    .clone()
+                    .try_into::<Vec<Value>>()
+                    .map_err(Error::custom)?;
+                let mut seq = serializer.serialize_seq(Some(ary.len()))?;
+                for value in ary {
+                    seq.serialize_element(&value)?;
+                }
+                seq.end()
+            }
+            Ruby::Hash => {
+                let pairs = self
+                    .clone()
+                    .try_into::<Vec<(Value, Value)>>()
+                    .map_err(Error::custom)?;
+                let mut map = serializer.serialize_map(Some(pairs.len()))?;
+                for (key, value) in pairs {
+                    map.serialize_entry(&key, &value)?;
+                }
+                map.end()
+            }
+            _ => Err(Error::custom(format!(
+                "cannot serialize Ruby {} value",
+                self.pretty_name()
+            ))),
+        }
+    }
+}
+
+impl Convert<serde_json::Value, Value> for Artichoke {
+    fn convert(&self, value: serde_json::Value) -> Value {
+        match value {
+            serde_json::Value::Null => self.convert(None::<Value>),
+            serde_json::Value::Bool(value) => self.convert(value),
+            serde_json::Value::Number(number) => {
+                if let Some(value) = number.as_i64() {
+                    self.convert(value)
+                } else {
+                    self.convert(number.as_f64().unwrap_or_default())
+                }
+            }
+            serde_json::Value::String(value) => self.convert(value),
+            serde_json::Value::Array(values) => {
+                let values = values
+                    .into_iter()
+                    .map(|value| self.convert(value))
+                    .collect::<Vec<Value>>();
+                self.convert(values)
+            }
+            serde_json::Value::Object(object) => {
+                let pairs = object
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let key: Value = self.convert(key);
+                        let value: Value = self.convert(value);
+                        (key, value)
+                    })
+                    .collect::<Vec<_>>();
+                self.convert(pairs)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::convert::Convert;
+    use crate::value::{Value, ValueLike};
+
+    #[test]
+    fn eval_and_serialize_hash_of_array() {
+        let interp = crate::interpreter().expect("init");
+        let json = interp
+            .eval_and_serialize(br#"{"key" => [1, 2, 3]}"#)
+            .expect("serialize");
+        assert_eq!(json, serde_json::json!({"key": [1, 2, 3]}));
+    }
+
+    #[test]
+    fn symbol_serializes_as_string() {
+        let interp = crate::interpreter().expect("init");
+        let json = interp
+            .eval_and_serialize(b"{name: :artichoke}")
+            .expect("serialize");
+        assert_eq!(json, serde_json::json!({"name": "artichoke"}));
+    }
+
+    #[test]
+    fn proc_is_not_serializable() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"proc { 1 }").expect("eval");
+        assert!(serde_json::to_value(&value).is_err());
+    }
+
+    #[test]
+    fn json_round_trips_through_the_interpreter() {
+        let interp = crate::interpreter().expect("init");
+        let json = serde_json::json!({"key": [1, 2, 3]});
+        let value: Value = interp.convert(json.clone());
+        let original = interp.eval(br#"{"key" => [1, 2, 3]}"#).expect("eval");
+        let eql = value.funcall::<bool>("==", &[original], None).expect("eq");
+        assert!(eql);
+        assert_eq!(serde_json::to_value(&value).expect("serialize"), json);
+    }
+}
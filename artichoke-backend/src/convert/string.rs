@@ -1,6 +1,6 @@
 use std::str;
 
-use crate::convert::{Convert, TryConvert};
+use crate::convert::{Convert, ConvertLossy, TryConvert};
 use crate::types::{Ruby, Rust};
 use crate::value::{Value, ValueLike};
 use crate::{Artichoke, ArtichokeError};
@@ -30,6 +30,26 @@ impl TryConvert<Value, String> for Artichoke {
     }
 }
 
+/// Convert a Ruby `String` to a Rust [`String`], replacing invalid UTF-8
+/// sequences with U+FFFD REPLACEMENT CHARACTER.
+///
+/// Use this converter for display purposes, for example `Object#to_s` of an
+/// object that may hold binary data. Extension methods that interpret string
+/// contents should use the strict [`TryConvert`] converter, which fails on
+/// invalid UTF-8.
+impl ConvertLossy<Value, String> for Artichoke {
+    fn convert_lossy(&self, value: Value) -> Result<String, ArtichokeError> {
+        let type_tag = value.ruby_type();
+        let bytes = value
+            .try_into::<&[u8]>()
+            .map_err(|_| ArtichokeError::ConvertToRust {
+                from: type_tag,
+                to: Rust::String,
+            })?;
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
 impl<'a> TryConvert<Value, &'a str> for Artichoke {
     fn try_convert(&self, value: Value) -> Result<&'a str, ArtichokeError> {
         let type_tag = value.ruby_type();
@@ -56,7 +76,7 @@ mod tests {
     use quickcheck_macros::quickcheck;
     use std::convert::TryFrom;
 
-    use crate::convert::Convert;
+    use crate::convert::{Convert, ConvertLossy};
     use crate::sys;
     use crate::types::{Ruby, Rust};
     use crate::value::ValueLike;
@@ -117,6 +137,38 @@ mod tests {
         value == expected
     }
 
+    #[test]
+    fn strict_convert_fails_on_invalid_utf8() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"\"\\xFF\"").expect("eval");
+        let result = value.try_into::<String>();
+        assert_eq!(
+            result,
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::String,
+                to: Rust::String,
+            })
+        );
+    }
+
+    #[test]
+    fn lossy_convert_replaces_invalid_utf8() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"\"abc\\xFF\"").expect("eval");
+        let result: String = interp.convert_lossy(value).expect("convert");
+        assert_eq!(result, "abc\u{FFFD}");
+        // The lossy converter is still strict about the source type.
+        let value = interp.eval(b"Object.new").expect("eval");
+        let result: Result<String, ArtichokeError> = interp.convert_lossy(value);
+        assert_eq!(
+            result,
+            Err(ArtichokeError::ConvertToRust {
+                from: Ruby::Object,
+                to: Rust::String,
+            })
+        );
+    }
+
     #[test]
     fn symbol_to_string() {
         let interp = crate::interpreter().expect("init");
@@ -7,8 +7,10 @@ use std::rc::Rc;
 
 use crate::class;
 use crate::convert::RustBackedValue;
+use crate::extn::core::exception::{self, Fatal, RubyException};
 use crate::module;
 use crate::sys;
+use crate::value::Value;
 use crate::{Artichoke, ArtichokeError};
 
 /// Typedef for an mruby free function for an [`mrb_value`](sys::mrb_value) with
@@ -44,6 +46,56 @@ pub unsafe extern "C" fn rust_data_free<T: 'static + RustBackedValue>(
     drop(data);
 }
 
+/// Rust-native constructor for [`MRB_TT_DATA`](sys::mrb_vtype::MRB_TT_DATA)
+/// classes.
+///
+/// Implementors construct the backing Rust object from the arguments passed
+/// to `initialize`. The [`rust_data_construct`] trampoline stores the
+/// constructed object in the receiver's data slot, which removes the need to
+/// hand-roll an `unsafe extern "C"` constructor and an
+/// [`mrb_data_type`](sys::mrb_data_type) for each Rust-backed class.
+pub trait DataConstructor
+where
+    Self: RustBackedValue,
+{
+    /// Construct `Self` from the arguments passed to `initialize`.
+    fn construct(interp: &Artichoke, args: &[Value]) -> Result<Self, Box<dyn RubyException>>;
+}
+
+/// A generic implementation of `initialize` for [`MRB_TT_DATA`] classes
+/// backed by a [`DataConstructor`].
+///
+/// The constructed object is injected into the receiver with
+/// [`sys::mrb_sys_data_init`] using the [`mrb_data_type`](sys::mrb_data_type)
+/// generated by [`class::Spec::new`]. Pair the class spec with
+/// [`rust_data_free`] so the VM can reclaim the object.
+///
+/// [`MRB_TT_DATA`]: sys::mrb_vtype::MRB_TT_DATA
+pub unsafe extern "C" fn rust_data_construct<T: DataConstructor>(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    let interp = unwrap_interpreter!(mrb);
+    let args = args
+        .iter()
+        .map(|arg| Value::new(&interp, *arg))
+        .collect::<Vec<_>>();
+    let result = T::construct(&interp, args.as_slice()).and_then(|data| {
+        unsafe { data.try_into_ruby(&interp, Some(slf)) }
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(Fatal::new(
+                    &interp,
+                    "Failed to store Rust object in Ruby receiver",
+                ))
+            })
+    });
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
 /// Typedef for a method exposed in the mruby interpreter.
 ///
 /// This function signature is used for all types of mruby methods, including
@@ -328,7 +380,7 @@ mod tests {
             let class = class::Spec::new("DefineMethodTestClass", None, None);
             class::Builder::for_spec(&interp, &class)
                 .add_method("value", value, sys::mrb_args_none())
-                .add_self_method("value", value, sys::mrb_args_none())
+                .add_class_method("value", value, sys::mrb_args_none())
                 .define()
                 .unwrap();
             interp.0.borrow_mut().def_class::<Class>(class);
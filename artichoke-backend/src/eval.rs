@@ -1,10 +1,11 @@
 use artichoke_core::eval::{self, Eval};
 use std::borrow::Cow;
-use std::ffi::{c_void, CString};
+use std::convert::TryFrom;
+use std::ffi::{c_void, CStr, CString};
 use std::io;
 use std::mem;
 
-use crate::exception::{ExceptionHandler, LastError};
+use crate::exception::{Exception, ExceptionHandler, LastError};
 use crate::sys::{self, DescribeState};
 use crate::value::Value;
 use crate::{Artichoke, ArtichokeError};
@@ -59,16 +60,36 @@ pub struct Context {
     /// Value of the `__FILE__` magic constant that also appears in stack
     /// frames.
     pub filename: Cow<'static, [u8]>,
+    /// Line number of the first line of code evaled in this context, which
+    /// offsets the `__LINE__` magic constant and backtrace line numbers.
+    ///
+    /// The parser records the last line it saw on the persistent
+    /// [`sys::mrbc_context`], so without an explicit line, line numbers in an
+    /// inner eval continue counting from the end of the outer source.
+    pub line: usize,
 }
 
 impl Context {
-    /// Create a new [`Context`].
+    /// Create a new [`Context`] whose source starts at line 1.
     pub fn new<T>(filename: T) -> Self
     where
         T: Into<Cow<'static, [u8]>>,
     {
         Self {
             filename: filename.into(),
+            line: 1,
+        }
+    }
+
+    /// Create a new [`Context`] whose source starts at the given line, e.g.
+    /// for evaling a fragment extracted from the middle of a file.
+    pub fn new_with_line<T>(filename: T, line: usize) -> Self
+    where
+        T: Into<Cow<'static, [u8]>>,
+    {
+        Self {
+            filename: filename.into(),
+            line,
         }
     }
 
@@ -80,7 +101,7 @@ impl Context {
 
     pub fn filename_as_cstring(&self) -> Result<CString, ArtichokeError> {
         CString::new(self.filename.as_ref()).map_err(|_| {
-            ArtichokeError::Vfs(io::Error::new(
+            ArtichokeError::Io(io::Error::new(
                 io::ErrorKind::Other,
                 "failed to convert context filename to CString",
             ))
@@ -96,6 +117,87 @@ impl Default for Context {
 
 impl eval::Context for Context {}
 
+/// Propagate the active eval [`Context`] filename to the persistent
+/// [`sys::mrbc_context`].
+///
+/// [`Eval::eval`] sets the filename before compiling code, but native code
+/// that parses sources or inspects the [`sys::mrbc_context`] between evals
+/// should also see the filename of the most recently pushed [`Context`].
+/// `mrbc_filename` copies the string into the mruby heap, so the [`CString`]
+/// may be dropped after this call.
+fn sync_context_filename(interp: &Artichoke) {
+    let (mrb, ctx, filename) = {
+        let api = interp.0.borrow();
+        if api.is_closed() {
+            return;
+        }
+        let filename = if let Some(context) = api.context_stack.last() {
+            context.filename_as_cstring()
+        } else {
+            Context::root().filename_as_cstring()
+        };
+        (api.mrb, api.ctx, filename)
+    };
+    if let Ok(filename) = filename {
+        unsafe {
+            sys::mrbc_filename(mrb, ctx, filename.as_ptr() as *const i8);
+        }
+    }
+}
+
+/// Convert a `SyntaxError` [`Exception`] raised during the compile step of
+/// eval into a structured [`ArtichokeError::SyntaxError`].
+///
+/// The [`sys::mrbc_context`] created by [`crate::interpreter`] has
+/// `capture_errors` set, so the parser reports errors with a `line NN:`
+/// prefix. The file name comes from the active eval [`Context`].
+fn syntax_error(interp: &Artichoke, exception: &Exception) -> ArtichokeError {
+    let file = {
+        let api = interp.0.borrow();
+        let filename = if let Some(context) = api.context_stack.last() {
+            context.filename.clone()
+        } else {
+            Context::root().filename
+        };
+        String::from_utf8_lossy(filename.as_ref()).into_owned()
+    };
+    let message = exception.message.trim_end();
+    let mut line = 0;
+    let mut detail = message;
+    if message.starts_with("line ") {
+        if let Some(pos) = message.find(':') {
+            if let Ok(parsed) = message["line ".len()..pos].parse::<usize>() {
+                line = parsed;
+                detail = message[pos + 1..].trim_start();
+            }
+        }
+    }
+    ArtichokeError::SyntaxError {
+        file,
+        line,
+        message: String::from(detail),
+    }
+}
+
+/// Convert a `LoadError` [`Exception`] raised by `Kernel#require` into a
+/// structured [`ArtichokeError::LoadError`].
+///
+/// The path is recovered from the canonical `cannot load such file -- path`
+/// message raised by the require implementation in
+/// `extn::core::kernel::require`. Exceptions with an unrecognized message,
+/// for example a `LoadError` raised by user code, are left to the caller to
+/// surface as [`ArtichokeError::Exec`].
+fn load_error(exception: &Exception) -> Option<ArtichokeError> {
+    const MISSING_FILE: &str = "cannot load such file -- ";
+    let message = exception.message.trim_end();
+    let pos = message.find(MISSING_FILE)?;
+    let path = &message[pos + MISSING_FILE.len()..];
+    let path = path.trim_matches('"');
+    Some(ArtichokeError::LoadError {
+        path: String::from(path),
+    })
+}
+
 impl Eval for Artichoke {
     type Context = Context;
 
@@ -107,22 +209,29 @@ impl Eval for Artichoke {
         // get access to the underlying `ArtichokeState`.
         let (mrb, ctx) = {
             let borrow = self.0.borrow();
+            if borrow.is_closed() {
+                return Err(ArtichokeError::InterpreterClosed);
+            }
             (borrow.mrb, borrow.ctx)
         };
 
         // Grab the persistent `Context` from the context on the `State` or
         // the root context if the stack is empty.
-        let filename = {
+        let (filename, line) = {
             let api = self.0.borrow();
             if let Some(context) = api.context_stack.last() {
-                context.filename_as_cstring()?
+                (context.filename_as_cstring()?, context.line)
             } else {
-                Context::root().filename_as_cstring()?
+                let root = Context::root();
+                (root.filename_as_cstring()?, root.line)
             }
         };
 
         unsafe {
             sys::mrbc_filename(mrb, ctx, filename.as_ptr() as *const i8);
+            // The parser stores the last line it saw back on the persistent
+            // context, so the line counter must be reset for each eval.
+            (*ctx).lineno = u16::try_from(line).unwrap_or(u16::max_value());
         }
 
         let protect = Protect::new(self, code);
@@ -140,10 +249,22 @@ impl Eval for Artichoke {
         };
         let value = Value::new(self, value);
 
+        // Give the next eval a fresh instruction budget if an instruction
+        // limit is set. Reset before exception extraction, which funcalls into
+        // the VM.
+        self.0.borrow_mut().reset_instruction_budget();
+
         match self.last_error() {
             LastError::Some(exception) => {
                 warn!("runtime error with exception backtrace: {}", exception);
-                Err(ArtichokeError::Exec(exception.to_string()))
+                if exception.class == "SyntaxError" {
+                    Err(syntax_error(self, &exception))
+                } else if exception.class == "LoadError" {
+                    Err(load_error(&exception)
+                        .unwrap_or_else(|| ArtichokeError::Exec(exception.to_string())))
+                } else {
+                    Err(ArtichokeError::Exec(exception.to_string()))
+                }
             }
             LastError::UnableToExtract(err) => {
                 error!("failed to extract exception after runtime error: {}", err);
@@ -161,28 +282,37 @@ impl Eval for Artichoke {
         }
     }
 
+    #[allow(deprecated)]
     fn unchecked_eval(&self, code: &[u8]) -> Self::Value {
         // Ensure the borrow is out of scope by the time we eval code since
         // Rust-backed files and types may need to mutably borrow the `Artichoke` to
         // get access to the underlying `ArtichokeState`.
         let (mrb, ctx) = {
             let borrow = self.0.borrow();
+            assert!(
+                !borrow.is_closed(),
+                "attempted to eval on a closed interpreter"
+            );
             (borrow.mrb, borrow.ctx)
         };
 
         // Grab the persistent `Context` from the context on the `State` or
         // the root context if the stack is empty.
-        let filename = {
+        let (filename, line) = {
             let api = self.0.borrow();
             if let Some(context) = api.context_stack.last() {
-                context.filename_as_cstring().unwrap()
+                (context.filename_as_cstring().unwrap(), context.line)
             } else {
-                Context::root().filename_as_cstring().unwrap()
+                let root = Context::root();
+                (root.filename_as_cstring().unwrap(), root.line)
             }
         };
 
         unsafe {
             sys::mrbc_filename(mrb, ctx, filename.as_ptr() as *const i8);
+            // The parser stores the last line it saw back on the persistent
+            // context, so the line counter must be reset for each eval.
+            (*ctx).lineno = u16::try_from(line).unwrap_or(u16::max_value());
         }
 
         let protect = Protect::new(self, code);
@@ -206,22 +336,131 @@ impl Eval for Artichoke {
             }
             value
         };
+        // Give the next eval a fresh instruction budget if an instruction
+        // limit is set.
+        self.0.borrow_mut().reset_instruction_budget();
         Value::new(self, value)
     }
 
+    fn check_syntax(&self, code: &[u8]) -> Result<(), ArtichokeError> {
+        let mrb = {
+            let borrow = self.0.borrow();
+            if borrow.is_closed() {
+                return Err(ArtichokeError::InterpreterClosed);
+            }
+            borrow.mrb
+        };
+        let file = {
+            let api = self.0.borrow();
+            let filename = if let Some(context) = api.context_stack.last() {
+                context.filename.clone()
+            } else {
+                Context::root().filename
+            };
+            String::from_utf8_lossy(filename.as_ref()).into_owned()
+        };
+        unsafe {
+            // Parse on a fresh context so parse-only runs do not disturb the
+            // state of the persistent eval context.
+            let ctx = sys::mrbc_context_new(mrb);
+            (*ctx).set_capture_errors(1);
+            let parser = sys::mrb_parse_nstring(mrb, code.as_ptr() as *const i8, code.len(), ctx);
+            if parser.is_null() {
+                sys::mrbc_context_free(mrb, ctx);
+                return Err(ArtichokeError::New);
+            }
+            let result = if (*parser).nerr > 0 {
+                let error = (*parser).error_buffer[0];
+                let message = if error.message.is_null() {
+                    String::from("syntax error")
+                } else {
+                    CStr::from_ptr(error.message).to_string_lossy().into_owned()
+                };
+                let line = usize::try_from(error.lineno).unwrap_or_default();
+                Err(ArtichokeError::SyntaxError {
+                    file,
+                    line,
+                    message,
+                })
+            } else {
+                Ok(())
+            };
+            sys::mrb_parser_free(parser);
+            sys::mrbc_context_free(mrb, ctx);
+            result
+        }
+    }
+
+    fn is_incomplete_expression(&self, code: &[u8]) -> bool {
+        let mrb = {
+            let borrow = self.0.borrow();
+            if borrow.is_closed() {
+                return false;
+            }
+            borrow.mrb
+        };
+        unsafe {
+            // Parse on a fresh context so parse-only runs do not disturb the
+            // state of the persistent eval context.
+            let ctx = sys::mrbc_context_new(mrb);
+            (*ctx).set_capture_errors(1);
+            let parser = sys::mrb_parse_nstring(mrb, code.as_ptr() as *const i8, code.len(), ctx);
+            if parser.is_null() {
+                sys::mrbc_context_free(mrb, ctx);
+                return false;
+            }
+            let result = if !(*parser).parsing_heredoc.is_null() || !(*parser).lex_strterm.is_null()
+            {
+                // An unterminated heredoc or string literal always expects
+                // more input.
+                true
+            } else if (*parser).nerr > 0 {
+                // The parser reports running out of input as an unexpected
+                // `$end` token. Any other diagnostic is a hard syntax error.
+                let error = (*parser).error_buffer[0];
+                if error.message.is_null() {
+                    false
+                } else {
+                    let message = CStr::from_ptr(error.message).to_string_lossy();
+                    message.starts_with("syntax error, unexpected $end")
+                }
+            } else {
+                // The parse succeeded, but the lexer may have stopped in a
+                // state that requires another token, e.g. after a trailing
+                // method call dot or a `class` keyword without a name.
+                match (*parser).lstate {
+                    sys::mrb_lex_state_enum::EXPR_DOT
+                    | sys::mrb_lex_state_enum::EXPR_CLASS
+                    | sys::mrb_lex_state_enum::EXPR_FNAME
+                    | sys::mrb_lex_state_enum::EXPR_VALUE => true,
+                    _ => false,
+                }
+            };
+            sys::mrb_parser_free(parser);
+            sys::mrbc_context_free(mrb, ctx);
+            result
+        }
+    }
+
     fn peek_context(&self) -> Option<Self::Context> {
         let api = self.0.borrow();
         api.context_stack.last().cloned()
     }
 
     fn push_context(&self, context: Self::Context) {
-        let mut api = self.0.borrow_mut();
-        api.context_stack.push(context);
+        {
+            let mut api = self.0.borrow_mut();
+            api.context_stack.push(context);
+        }
+        sync_context_filename(self);
     }
 
     fn pop_context(&self) {
-        let mut api = self.0.borrow_mut();
-        api.context_stack.pop();
+        {
+            let mut api = self.0.borrow_mut();
+            api.context_stack.pop();
+        }
+        sync_context_filename(self);
     }
 }
 
@@ -238,6 +477,77 @@ mod tests {
     use crate::value::{Value, ValueLike};
     use crate::{Artichoke, ArtichokeError};
 
+    #[test]
+    fn check_syntax_does_not_execute_code() {
+        let interp = crate::interpreter().expect("init");
+        interp.check_syntax(b"$check = 255").expect("valid syntax");
+        // Parse-only runs must not execute side effects.
+        let result = interp.eval(b"$check").expect("eval");
+        assert_eq!(result.try_into::<Option<i64>>().expect("convert"), None);
+    }
+
+    #[test]
+    fn check_syntax_reports_syntax_errors() {
+        let interp = crate::interpreter().expect("init");
+        let err = interp.check_syntax(b"def foo; 1 +").unwrap_err();
+        match err {
+            ArtichokeError::SyntaxError { file, line, .. } => {
+                assert_eq!(file, "(eval)");
+                assert!(line > 0);
+            }
+            err => panic!("expected ArtichokeError::SyntaxError, got {:?}", err),
+        }
+        // The eval context is unaffected by failed syntax checks.
+        let result = interp.eval(b"2 + 2").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 4);
+    }
+
+    #[test]
+    fn incomplete_expressions_expect_more_input() {
+        let interp = crate::interpreter().expect("init");
+        assert!(interp.is_incomplete_expression(b"def foo"));
+        assert!(interp.is_incomplete_expression(b"[1, 2,"));
+        assert!(interp.is_incomplete_expression(b"'unterminated"));
+        assert!(interp.is_incomplete_expression(b"Foo."));
+    }
+
+    #[test]
+    fn complete_and_erroneous_expressions_are_not_incomplete() {
+        let interp = crate::interpreter().expect("init");
+        assert!(!interp.is_incomplete_expression(b"2 + 2"));
+        assert!(!interp.is_incomplete_expression(b"def foo; end"));
+        // Too many `end`s is a hard syntax error, not partial input.
+        assert!(!interp.is_incomplete_expression(b"def foo; end; end"));
+        // Parse-only runs must not execute side effects.
+        assert!(!interp.is_incomplete_expression(b"$incomplete = 255"));
+        let result = interp.eval(b"$incomplete").expect("eval");
+        assert_eq!(result.try_into::<Option<i64>>().expect("convert"), None);
+    }
+
+    #[test]
+    fn require_missing_file_is_a_typed_load_error() {
+        let interp = crate::interpreter().expect("init");
+        let err = interp.eval(b"require 'nonexistent'").map(|_| ()).unwrap_err();
+        match err {
+            ArtichokeError::LoadError { path } => assert_eq!(path, "nonexistent"),
+            err => panic!("expected ArtichokeError::LoadError, got {:?}", err),
+        }
+        // A `LoadError` raised by Ruby code is still rescuable and does not
+        // map to the typed variant.
+        let result = interp
+            .eval(b"begin; require 'nonexistent'; rescue LoadError; :rescued; end")
+            .expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "rescued");
+        let err = interp
+            .eval(b"raise LoadError, 'broken archive'")
+            .map(|_| ())
+            .unwrap_err();
+        match err {
+            ArtichokeError::Exec(message) => assert!(message.contains("broken archive")),
+            err => panic!("expected ArtichokeError::Exec, got {:?}", err),
+        }
+    }
+
     #[test]
     fn root_eval_context() {
         let interp = crate::interpreter().expect("init");
@@ -331,23 +641,110 @@ NestedEval.file
     }
 
     #[test]
-    fn unparseable_code_returns_err_syntax_error() {
+    fn context_filename_sets_file_and_dir_magic_constants() {
         let interp = crate::interpreter().expect("init");
-        let result = interp.eval(b"'a").map(|_| ());
+        interp.push_context(Context::new(b"/app/lib/foo.rb".as_ref()));
+        let result = interp.eval(b"__FILE__").expect("eval");
         assert_eq!(
-            result,
-            Err(ArtichokeError::Exec("SyntaxError: syntax error".to_owned()))
+            result.try_into::<&str>().expect("convert"),
+            "/app/lib/foo.rb"
+        );
+        let result = interp.eval(b"__dir__").expect("eval");
+        assert_eq!(result.try_into::<&str>().expect("convert"), "/app/lib");
+        interp.pop_context();
+        // `__dir__` is `nil` on the root "(eval)" context.
+        let result = interp.eval(b"__dir__").expect("eval");
+        assert!(result.try_into::<Option<&str>>().expect("convert").is_none());
+    }
+
+    #[test]
+    fn push_context_propagates_filename_to_mrbc_context() {
+        let interp = crate::interpreter().expect("init");
+        interp.push_context(Context::new(b"/app/lib/foo.rb".as_ref()));
+        let filename = {
+            let api = interp.0.borrow();
+            let ctx = api.ctx;
+            unsafe { std::ffi::CStr::from_ptr((*ctx).filename) }
+                .to_string_lossy()
+                .into_owned()
+        };
+        assert_eq!(filename, "/app/lib/foo.rb");
+        interp.pop_context();
+        let filename = {
+            let api = interp.0.borrow();
+            let ctx = api.ctx;
+            unsafe { std::ffi::CStr::from_ptr((*ctx).filename) }
+                .to_string_lossy()
+                .into_owned()
+        };
+        assert_eq!(filename, "(eval)");
+    }
+
+    #[test]
+    fn eval_infallible_returns_value() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval_infallible(b"2 + 2");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to eval Ruby code")]
+    fn eval_infallible_panics_on_raise() {
+        let interp = crate::interpreter().expect("init");
+        let _ = interp.eval_infallible(b"raise 'boom'");
+    }
+
+    #[test]
+    fn line_numbers_are_file_local_in_nested_eval() {
+        let interp = crate::interpreter().expect("init");
+        // Leave the parser's line counter pointing past line 100 on the
+        // persistent context.
+        let padding = "\n".repeat(100);
+        interp.eval(padding.as_bytes()).expect("eval");
+        interp.push_context(Context::new(b"inner_file.rb".as_ref()));
+        let err = interp.eval(b"\n\nraise 'boom'").map(|_| ()).unwrap_err();
+        interp.pop_context();
+        let err = format!("{}", err);
+        assert!(
+            err.contains("inner_file.rb:3"),
+            "expected backtrace with file-local line number, got {}",
+            err
         );
     }
 
+    #[test]
+    fn context_with_line_offset() {
+        let interp = crate::interpreter().expect("init");
+        interp.push_context(Context::new_with_line(b"fragment.rb".as_ref(), 100));
+        let result = interp.eval(b"__LINE__").expect("eval");
+        interp.pop_context();
+        assert_eq!(result.try_into::<i64>().expect("convert"), 100);
+    }
+
+    #[test]
+    fn unparseable_code_returns_err_syntax_error() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"15\n'a").map(|_| ());
+        match result {
+            Err(ArtichokeError::SyntaxError { file, line, .. }) => {
+                assert_eq!(file, "(eval)");
+                assert_eq!(line, 2);
+            }
+            result => panic!("expected SyntaxError, got {:?}", result),
+        }
+    }
+
     #[test]
     fn interpreter_is_usable_after_syntax_error() {
         let interp = crate::interpreter().expect("init");
         let result = interp.eval(b"'a").map(|_| ());
-        assert_eq!(
-            result,
-            Err(ArtichokeError::Exec("SyntaxError: syntax error".to_owned()))
-        );
+        match result {
+            Err(ArtichokeError::SyntaxError { file, line, .. }) => {
+                assert_eq!(file, "(eval)");
+                assert_eq!(line, 1);
+            }
+            result => panic!("expected SyntaxError, got {:?}", result),
+        }
         // Ensure interpreter is usable after evaling unparseable code
         let result = interp.eval(b"'a' * 10 ").expect("eval");
         let result = result.try_into::<&str>().expect("convert");
@@ -383,7 +780,12 @@ NestedEval.file
             .def_rb_source_file(b"fail.rb", &b"def bad; 'as'.scan(; end"[..])
             .expect("def file");
         let result = interp.eval(b"require 'fail'").map(|_| ());
-        let expected = ArtichokeError::Exec("SyntaxError: syntax error".to_owned());
-        assert_eq!(result, Err(expected));
+        match result {
+            Err(ArtichokeError::SyntaxError { file, line, .. }) => {
+                assert_eq!(file, "/src/lib/fail.rb");
+                assert_eq!(line, 1);
+            }
+            result => panic!("expected SyntaxError, got {:?}", result),
+        }
     }
 }
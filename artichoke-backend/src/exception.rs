@@ -158,8 +158,13 @@ mod tests {
     fn return_exception_with_no_backtrace() {
         let interp = crate::interpreter().expect("init");
         let result = interp.eval(b"def bad; (; end").map(|_| ());
-        let expected = Exception::new("SyntaxError", "waffles", None, "SyntaxError: syntax error");
-        assert_eq!(result, Err(ArtichokeError::Exec(expected.to_string())));
+        match result {
+            Err(ArtichokeError::SyntaxError { line, message, .. }) => {
+                assert_eq!(line, 1);
+                assert!(message.contains("syntax error"));
+            }
+            result => panic!("expected SyntaxError, got {:?}", result),
+        }
     }
 
     #[test]
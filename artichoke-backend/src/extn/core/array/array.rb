@@ -346,69 +346,6 @@ class Array
     self
   end
 
-  def combination(k, &block) # rubocop:disable Naming/UncommunicativeMethodParamName
-    k =
-      if k.is_a?(Integer)
-        k
-      elsif k.nil?
-        raise TypeError, 'no implicit conversion from nil to integer'
-      elsif k.respond_to?(:to_int)
-        classname = k.class
-        classname = k.inspect if k.equal?(false) || k.equal?(true)
-        k = k.to_int
-        raise TypeError, "can't convert #{classname} to Integer (#{classname}#to_int gives #{k.class})" unless k.is_a?(Integer)
-
-        k
-      else
-        classname = k.class
-        classname = k.inspect if k.equal?(false) || k.equal?(true)
-        raise TypeError, "no implicit conversion of #{classname} into Integer"
-      end
-
-    return to_enum(:combination, k) unless block
-    return self if k > length
-    return self if k.negative?
-
-    if k.zero?
-      block.call([])
-    elsif k == 1
-      ary = dup
-      len = length
-      idx = 0
-      while idx < len
-        block.call([ary[idx]])
-        idx += 1
-      end
-    elsif k == length
-      block.call(dup)
-    else
-      ary = dup
-      len = length
-      indexes = (0...k).to_a
-      incr = k - 1
-      loop do
-        while indexes[incr] < len
-          block.call(indexes.map { |i| ary[i] })
-          indexes[incr] += 1
-        end
-
-        reset = incr
-        until reset.negative?
-          reset -= 1
-          prev = indexes[reset]
-          break unless prev + 1 >= len
-        end
-        base = indexes[reset] + 1
-        replace = k - reset
-        indexes[reset, replace] = (base...(base + replace)).to_a
-        break if indexes[0] + k > len
-
-        incr = k - 1
-      end
-    end
-    self
-  end
-
   def compact
     reject(&:nil?)
   end
@@ -828,30 +765,6 @@ class Array
     self[0, count]
   end
 
-  def flatten(depth = nil)
-    res = dup
-    res.flatten! depth
-    res
-  end
-
-  def flatten!(depth = nil)
-    modified = false
-    ar = []
-    idx = 0
-    len = length
-    while idx < len
-      e = self[idx]
-      if e.is_a?(Array) && (depth.nil? || depth.positive?)
-        ar.concat(e.flatten(depth.nil? ? nil : depth - 1))
-        modified = true
-      else
-        ar << e
-      end
-      idx += 1
-    end
-    self[0, len] = ar if modified
-  end
-
   def include?(object)
     idx = 0
     len = length
@@ -1012,34 +925,6 @@ class Array
     true
   end
 
-  def permutation(kcombinations = size, &block)
-    size = self.size
-    return to_enum(:permutation, kcombinations) unless block
-    return if kcombinations > size
-
-    if kcombinations.zero?
-      yield []
-    else
-      i = 0
-      while i < size
-        result = [self[i]]
-        if (kcombinations - 1).positive?
-          ary = self[0...i] + self[i + 1..-1]
-          ary.permutation(kcombinations - 1) do |c|
-            yield result + c
-          end
-        else
-          yield result
-        end
-        i += 1
-      end
-    end
-  end
-
-  def product(*_args)
-    raise NotImplementedError
-  end
-
   def push(*args)
     raise FrozenError, "can't modify frozen Array" if frozen?
 
@@ -2,19 +2,14 @@ use artichoke_core::eval::Eval;
 #[cfg(feature = "artichoke-array")]
 use std::convert::TryFrom;
 
-#[cfg(feature = "artichoke-array")]
 use crate::class;
 #[cfg(feature = "artichoke-array")]
 use crate::convert::Convert;
 #[cfg(feature = "artichoke-array")]
 use crate::def;
-#[cfg(feature = "artichoke-array")]
 use crate::extn::core::array;
-#[cfg(feature = "artichoke-array")]
 use crate::extn::core::exception;
-#[cfg(feature = "artichoke-array")]
 use crate::sys;
-#[cfg(feature = "artichoke-array")]
 use crate::value::Value;
 use crate::{Artichoke, ArtichokeError};
 
@@ -32,7 +27,14 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
             ary_element_assignment,
             sys::mrb_args_req_and_opt(2, 1),
         )
+        .add_method(
+            "combination",
+            ary_combination,
+            sys::mrb_args_req(1) | sys::mrb_args_block(),
+        )
         .add_method("concat", ary_concat, sys::mrb_args_any())
+        .add_method("flatten", ary_flatten, sys::mrb_args_opt(1))
+        .add_method("flatten!", ary_flatten_bang, sys::mrb_args_opt(1))
         .add_method(
             "initialize",
             ary_initialize,
@@ -40,7 +42,13 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         )
         .add_method("initialize_copy", ary_initialize_copy, sys::mrb_args_req(1))
         .add_method("length", ary_len, sys::mrb_args_none())
+        .add_method(
+            "permutation",
+            ary_permutation,
+            sys::mrb_args_opt(1) | sys::mrb_args_block(),
+        )
         .add_method("pop", ary_pop, sys::mrb_args_none())
+        .add_method("product", ary_product, sys::mrb_args_any())
         .add_method("reverse!", ary_reverse_bang, sys::mrb_args_none())
         .add_method("size", ary_len, sys::mrb_args_none())
         .define()?;
@@ -52,6 +60,26 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 
 #[cfg(not(feature = "artichoke-array"))]
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    if interp.0.borrow().class_spec::<array::Array>().is_some() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Array", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method(
+            "combination",
+            ary_combination,
+            sys::mrb_args_req(1) | sys::mrb_args_block(),
+        )
+        .add_method("flatten", ary_flatten, sys::mrb_args_opt(1))
+        .add_method("flatten!", ary_flatten_bang, sys::mrb_args_opt(1))
+        .add_method(
+            "permutation",
+            ary_permutation,
+            sys::mrb_args_opt(1) | sys::mrb_args_block(),
+        )
+        .add_method("product", ary_product, sys::mrb_args_any())
+        .define()?;
+    interp.0.borrow_mut().def_class::<array::Array>(spec);
     interp.eval(&include_bytes!("array.rb")[..])?;
     trace!("Patched Array onto interpreter");
     Ok(())
@@ -201,3 +229,164 @@ unsafe extern "C" fn ary_element_assignment(
         Err(exception) => exception::raise(interp, exception),
     }
 }
+
+unsafe extern "C" fn ary_flatten(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    let depth = mrb_get_args!(mrb, optional = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let array = Value::new(&interp, ary);
+    let depth = depth.map(|depth| Value::new(&interp, depth));
+    let result = array::trampoline::flatten(&interp, array, depth);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+unsafe extern "C" fn ary_flatten_bang(
+    mrb: *mut sys::mrb_state,
+    ary: sys::mrb_value,
+) -> sys::mrb_value {
+    let depth = mrb_get_args!(mrb, optional = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let array = Value::new(&interp, ary);
+    let depth = depth.map(|depth| Value::new(&interp, depth));
+    let result = array::trampoline::flatten_bang(&interp, array, depth);
+    match result {
+        Ok(value) => {
+            let basic = sys::mrb_sys_basic_ptr(ary);
+            sys::mrb_write_barrier(mrb, basic);
+            value.inner()
+        }
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+unsafe extern "C" fn ary_product(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    let interp = unwrap_interpreter!(mrb);
+    let array = Value::new(&interp, ary);
+    let others = args
+        .iter()
+        .map(|other| Value::new(&interp, *other))
+        .collect::<Vec<_>>();
+    let result = array::trampoline::product(&interp, array, others);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+unsafe extern "C" fn ary_combination(
+    mrb: *mut sys::mrb_state,
+    ary: sys::mrb_value,
+) -> sys::mrb_value {
+    let (n, block) = mrb_get_args!(mrb, required = 1, &block);
+    let interp = unwrap_interpreter!(mrb);
+    let array = Value::new(&interp, ary);
+    let n = Value::new(&interp, n);
+    let result = array::trampoline::combination(&interp, array, n, block);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+unsafe extern "C" fn ary_permutation(
+    mrb: *mut sys::mrb_state,
+    ary: sys::mrb_value,
+) -> sys::mrb_value {
+    let (n, block) = mrb_get_args!(mrb, optional = 1, &block);
+    let interp = unwrap_interpreter!(mrb);
+    let array = Value::new(&interp, ary);
+    let n = n.map(|n| Value::new(&interp, n));
+    let result = array::trampoline::permutation(&interp, array, n, block);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+
+    #[test]
+    fn flatten_expands_nested_arrays() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"[[1, [2]], [[3]]].flatten").expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<i64>>().expect("convert"),
+            vec![1, 2, 3]
+        );
+        let result = interp
+            .eval(b"[[1, [2]], [[3]]].flatten(1) == [1, [2], [3]]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"ary = [1, [2]]; ary.flatten; ary == [1, [2]]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn flatten_bang_mutates_and_respects_frozen_state() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"ary = [1, [2]]; ary.flatten!; ary == [1, 2]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"[1, 2].flatten!.nil?").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"begin; [1, [2]].freeze.flatten!; rescue FrozenError; :frozen; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("frozen")
+        );
+    }
+
+    #[test]
+    fn product_generates_cartesian_product() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"[1, 2].product([3, 4]) == [[1, 3], [1, 4], [2, 3], [2, 4]]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"[1].product == [[1]]").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"begin; [1].product(2); rescue TypeError; :refused; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("refused")
+        );
+    }
+
+    #[test]
+    fn combination_and_permutation_yield_tuples() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"[1, 2].permutation(2).to_a == [[1, 2], [2, 1]]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"[1, 2].combination(1).to_a == [[1], [2]]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"[1, 2, 3].combination(2).to_a == [[1, 2], [1, 3], [2, 3]]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"[1, 2, 3].permutation.to_a.length")
+            .expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 6);
+        let result = interp
+            .eval(b"tuples = []; [1, 2].combination(1) { |t| tuples << t }; tuples == [[1], [2]]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+}
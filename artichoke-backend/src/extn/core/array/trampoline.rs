@@ -1,9 +1,11 @@
 use artichoke_core::value::Value as _;
+use std::convert::TryFrom;
 
 use crate::convert::{Convert, RustBackedValue};
 use crate::extn::core::array::Array;
-use crate::extn::core::exception::{Fatal, FrozenError, RubyException};
+use crate::extn::core::exception::{Fatal, FrozenError, RubyException, RuntimeError, TypeError};
 use crate::gc::MrbGarbageCollection;
+use crate::types::Int;
 use crate::value::{Block, Value};
 use crate::Artichoke;
 
@@ -189,6 +191,281 @@ pub fn initialize(
     Array::initialize(interp, first, second, block, ary)
 }
 
+pub fn flatten(
+    interp: &Artichoke,
+    ary: Value,
+    depth: Option<Value>,
+) -> Result<Value, Box<dyn RubyException>> {
+    let depth = flatten_depth(depth)?;
+    let elements = elements(interp, &ary)?;
+    let mut flattened = Vec::new();
+    flatten_into(&elements, depth, &mut flattened);
+    Ok(interp.convert(flattened))
+}
+
+pub fn flatten_bang(
+    interp: &Artichoke,
+    ary: Value,
+    depth: Option<Value>,
+) -> Result<Value, Box<dyn RubyException>> {
+    if ary.is_frozen() {
+        return Err(Box::new(FrozenError::new(
+            interp,
+            "can't modify frozen Array",
+        )));
+    }
+    let depth = flatten_depth(depth)?;
+    let elements = elements(interp, &ary)?;
+    let mut flattened = Vec::new();
+    let modified = flatten_into(&elements, depth, &mut flattened);
+    if !modified {
+        return Ok(interp.convert(None::<Value>));
+    }
+    let replacement = interp.convert(flattened);
+    ary.funcall::<Value>("replace", &[replacement], None)
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(interp, "Unable to replace Array contents"))
+        })?;
+    Ok(ary)
+}
+
+pub fn product(
+    interp: &Artichoke,
+    ary: Value,
+    others: Vec<Value>,
+) -> Result<Value, Box<dyn RubyException>> {
+    let mut pools = Vec::with_capacity(others.len() + 1);
+    pools.push(elements(interp, &ary)?);
+    for other in others {
+        let pool = other
+            .clone()
+            .try_into::<Vec<Value>>()
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(TypeError::new(
+                    interp,
+                    format!(
+                        "no implicit conversion of {} into Array",
+                        other.pretty_name()
+                    ),
+                ))
+            })?;
+        pools.push(pool);
+    }
+    let mut tuples: Vec<Vec<Value>> = vec![Vec::new()];
+    for pool in &pools {
+        let mut next = Vec::with_capacity(tuples.len() * pool.len());
+        for tuple in &tuples {
+            for item in pool {
+                let mut tuple = tuple.clone();
+                tuple.push(item.clone());
+                next.push(tuple);
+            }
+        }
+        tuples = next;
+    }
+    let tuples = tuples
+        .into_iter()
+        .map(|tuple| interp.convert(tuple))
+        .collect::<Vec<Value>>();
+    Ok(interp.convert(tuples))
+}
+
+pub fn combination(
+    interp: &Artichoke,
+    ary: Value,
+    n: Value,
+    block: Option<Block>,
+) -> Result<Value, Box<dyn RubyException>> {
+    let block = if let Some(block) = block {
+        block
+    } else {
+        return enumerator(interp, &ary, "combination", n);
+    };
+    let elements = elements(interp, &ary)?;
+    let k = n.implicitly_convert_to_int()?;
+    // Negative lengths yield no combinations.
+    if let Ok(k) = usize::try_from(k) {
+        for tuple in combinations(&elements, k) {
+            block
+                .yield_arg(interp, &interp.convert(tuple))
+                .map_err(|_| {
+                    RuntimeError::new(interp, "exception during Array#combination block")
+                })?;
+        }
+    }
+    Ok(ary)
+}
+
+pub fn permutation(
+    interp: &Artichoke,
+    ary: Value,
+    n: Option<Value>,
+    block: Option<Block>,
+) -> Result<Value, Box<dyn RubyException>> {
+    let block = if let Some(block) = block {
+        block
+    } else {
+        let n = n.unwrap_or_else(|| interp.convert(None::<Value>));
+        return enumerator(interp, &ary, "permutation", n);
+    };
+    let elements = elements(interp, &ary)?;
+    let k = if let Some(n) = n {
+        n.implicitly_convert_to_int()?
+    } else {
+        Int::try_from(elements.len())
+            .map_err(|_| Fatal::new(interp, "Array length exceeds Integer max"))?
+    };
+    // Negative lengths yield no permutations.
+    if let Ok(k) = usize::try_from(k) {
+        for tuple in permutations(&elements, k) {
+            block
+                .yield_arg(interp, &interp.convert(tuple))
+                .map_err(|_| {
+                    RuntimeError::new(interp, "exception during Array#permutation block")
+                })?;
+        }
+    }
+    Ok(ary)
+}
+
+/// Return an `Enumerator` for a blockless call to an iteration method.
+fn enumerator(
+    interp: &Artichoke,
+    ary: &Value,
+    method: &str,
+    arg: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let args = if arg.is_nil() {
+        vec![interp.convert(method)]
+    } else {
+        vec![interp.convert(method), arg]
+    };
+    ary.funcall::<Value>("to_enum", args.as_slice(), None)
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(interp, "Unable to create Enumerator"))
+        })
+}
+
+/// Extract an `Array` receiver's elements.
+fn elements(interp: &Artichoke, ary: &Value) -> Result<Vec<Value>, Box<dyn RubyException>> {
+    ary.clone()
+        .try_into::<Vec<Value>>()
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(
+                interp,
+                "Unable to extract Vec<Value> from Ruby Array receiver",
+            ))
+        })
+}
+
+/// Normalize a `#flatten` depth argument. `nil` and negative depths flatten
+/// recursively without limit.
+fn flatten_depth(depth: Option<Value>) -> Result<Option<Int>, Box<dyn RubyException>> {
+    let depth = match depth {
+        Some(ref depth) if !depth.is_nil() => depth.implicitly_convert_to_int()?,
+        _ => return Ok(None),
+    };
+    if depth < 0 {
+        Ok(None)
+    } else {
+        Ok(Some(depth))
+    }
+}
+
+/// Recursively append elements, expanding nested `Array`s up to `depth`
+/// levels. Returns whether any nested `Array` was expanded.
+fn flatten_into(elements: &[Value], depth: Option<Int>, flattened: &mut Vec<Value>) -> bool {
+    let mut modified = false;
+    for element in elements {
+        if depth != Some(0) {
+            if let Ok(nested) = element.clone().try_into::<Vec<Value>>() {
+                modified = true;
+                flatten_into(&nested, depth.map(|depth| depth - 1), flattened);
+                continue;
+            }
+        }
+        flattened.push(element.clone());
+    }
+    modified
+}
+
+/// All length-`k` combinations of `elements` in lexicographic index order.
+fn combinations(elements: &[Value], k: usize) -> Vec<Vec<Value>> {
+    let len = elements.len();
+    if k > len {
+        return Vec::new();
+    }
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    let mut indices = (0..k).collect::<Vec<_>>();
+    let mut result = Vec::new();
+    loop {
+        result.push(
+            indices
+                .iter()
+                .map(|&index| elements[index].clone())
+                .collect(),
+        );
+        // Advance the rightmost index that has room to move.
+        let mut position = k;
+        loop {
+            if position == 0 {
+                return result;
+            }
+            position -= 1;
+            if indices[position] != position + len - k {
+                break;
+            }
+            if position == 0 {
+                return result;
+            }
+        }
+        indices[position] += 1;
+        for index in position + 1..k {
+            indices[index] = indices[index - 1] + 1;
+        }
+    }
+}
+
+/// All length-`k` permutations of `elements` in lexicographic index order.
+fn permutations(elements: &[Value], k: usize) -> Vec<Vec<Value>> {
+    fn permute(
+        elements: &[Value],
+        k: usize,
+        used: &mut Vec<bool>,
+        current: &mut Vec<Value>,
+        result: &mut Vec<Vec<Value>>,
+    ) {
+        if current.len() == k {
+            result.push(current.clone());
+            return;
+        }
+        for index in 0..elements.len() {
+            if used[index] {
+                continue;
+            }
+            used[index] = true;
+            current.push(elements[index].clone());
+            permute(elements, k, used, current, result);
+            current.pop();
+            used[index] = false;
+        }
+    }
+
+    if k > elements.len() {
+        return Vec::new();
+    }
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    let mut used = vec![false; elements.len()];
+    let mut current = Vec::with_capacity(k);
+    let mut result = Vec::new();
+    permute(elements, k, &mut used, &mut current, &mut result);
+    result
+}
+
 pub fn initialize_copy(
     interp: &Artichoke,
     ary: Value,
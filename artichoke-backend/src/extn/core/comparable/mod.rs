@@ -1,6 +1,13 @@
 use artichoke_core::eval::Eval;
+use artichoke_core::value::Value as _;
+use std::cmp::Ordering;
 
+use crate::convert::Convert;
+use crate::extn::core::exception::{self, ArgumentError, Fatal, RubyException, TypeError};
 use crate::module;
+use crate::sys;
+use crate::types::{Float, Int, Ruby};
+use crate::value::Value;
 use crate::{Artichoke, ArtichokeError};
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
@@ -8,7 +15,14 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         return Ok(());
     }
     let spec = module::Spec::new("Comparable", None);
-    module::Builder::for_spec(interp, &spec).define()?;
+    module::Builder::for_spec(interp, &spec)
+        .add_method("<", Comparable::lt, sys::mrb_args_req(1))
+        .add_method("<=", Comparable::le, sys::mrb_args_req(1))
+        .add_method(">", Comparable::gt, sys::mrb_args_req(1))
+        .add_method(">=", Comparable::ge, sys::mrb_args_req(1))
+        .add_method("between?", Comparable::between, sys::mrb_args_req(2))
+        .add_method("clamp", Comparable::clamp, sys::mrb_args_req_and_opt(1, 1))
+        .define()?;
     interp.0.borrow_mut().def_module::<Comparable>(spec);
     interp.eval(&include_bytes!("comparable.rb")[..])?;
     trace!("Patched Comparable onto interpreter");
@@ -16,3 +30,341 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 }
 
 pub struct Comparable;
+
+impl Comparable {
+    /// Delegate to `self <=> other` and interpret the result.
+    ///
+    /// Per MRI, a comparison fails if `<=>` returns `nil` or a non-numeric
+    /// value. Failed comparisons raise `ArgumentError`.
+    fn compare(
+        interp: &Artichoke,
+        value: &Value,
+        other: &Value,
+    ) -> Result<Ordering, Box<dyn RubyException>> {
+        let cmp = value
+            .funcall::<Value>("<=>", &[other.clone()], None)
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(ArgumentError::new(
+                    interp,
+                    Self::failed_comparison(value, other),
+                ))
+            })?;
+        match cmp.ruby_type() {
+            Ruby::Fixnum => {
+                let cmp = cmp.try_into::<Int>().map_err(|_| -> Box<dyn RubyException> {
+                    Box::new(Fatal::new(
+                        interp,
+                        "Failed to convert Ruby Integer spaceship result into Rust Int",
+                    ))
+                })?;
+                Ok(cmp.cmp(&0))
+            }
+            Ruby::Float => {
+                let cmp = cmp
+                    .try_into::<Float>()
+                    .map_err(|_| -> Box<dyn RubyException> {
+                        Box::new(Fatal::new(
+                            interp,
+                            "Failed to convert Ruby Float spaceship result into Rust Float",
+                        ))
+                    })?;
+                cmp.partial_cmp(&0.0)
+                    .ok_or_else(|| -> Box<dyn RubyException> {
+                        Box::new(ArgumentError::new(
+                            interp,
+                            Self::failed_comparison(value, other),
+                        ))
+                    })
+            }
+            _ => Err(Box::new(ArgumentError::new(
+                interp,
+                Self::failed_comparison(value, other),
+            ))),
+        }
+    }
+
+    // ```txt
+    // [2.6.3] > 'a' < 1
+    // ArgumentError (comparison of String with 1 failed)
+    // [2.6.3] > 'a' < nil
+    // ArgumentError (comparison of String with nil failed)
+    // [2.6.3] > 'a' < []
+    // ArgumentError (comparison of String with Array failed)
+    // ```
+    fn failed_comparison(value: &Value, other: &Value) -> String {
+        let classname = match other.ruby_type() {
+            // MRI uses `inspect` for `nil`, `true`, `false`, and `Numeric`s.
+            Ruby::Nil | Ruby::Bool | Ruby::Fixnum | Ruby::Float => other.inspect(),
+            _ => String::from(other.pretty_name()),
+        };
+        format!(
+            "comparison of {} with {} failed",
+            value.pretty_name(),
+            classname
+        )
+    }
+
+    fn clamp_with_bounds(
+        interp: &Artichoke,
+        value: &Value,
+        min: &Value,
+        max: &Value,
+    ) -> Result<Value, Box<dyn RubyException>> {
+        let bounds = min
+            .funcall::<Value>("<=>", &[max.clone()], None)
+            .ok()
+            .and_then(|cmp| cmp.try_into::<Int>().ok());
+        match bounds {
+            Some(cmp) if cmp <= 0 => {}
+            _ => {
+                return Err(Box::new(ArgumentError::new(
+                    interp,
+                    "min argument must be smaller than max argument",
+                )))
+            }
+        }
+        if Self::compare(interp, value, min)? == Ordering::Less {
+            return Ok(min.clone());
+        }
+        if Self::compare(interp, value, max)? == Ordering::Greater {
+            return Ok(max.clone());
+        }
+        Ok(value.clone())
+    }
+
+    fn clamp_with_range(
+        interp: &Artichoke,
+        value: &Value,
+        range: &Value,
+    ) -> Result<Value, Box<dyn RubyException>> {
+        if range
+            .funcall::<bool>("exclude_end?", &[], None)
+            .unwrap_or_default()
+        {
+            return Err(Box::new(ArgumentError::new(
+                interp,
+                "cannot clamp with an exclusive range",
+            )));
+        }
+        let min = range
+            .funcall::<Value>("begin", &[], None)
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(Fatal::new(interp, "Range#begin failed in Comparable#clamp"))
+            })?;
+        let max = range
+            .funcall::<Value>("end", &[], None)
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(Fatal::new(interp, "Range#end failed in Comparable#clamp"))
+            })?;
+        // Beginless and endless ranges clamp against only one bound.
+        if min.ruby_type() != Ruby::Nil && Self::compare(interp, value, &min)? == Ordering::Less {
+            return Ok(min);
+        }
+        if max.ruby_type() != Ruby::Nil && Self::compare(interp, value, &max)? == Ordering::Greater
+        {
+            return Ok(max);
+        }
+        Ok(value.clone())
+    }
+
+    pub unsafe extern "C" fn lt(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let other = Value::new(&interp, other);
+        let result = Self::compare(&interp, &value, &other)
+            .map(|cmp| interp.convert(cmp == Ordering::Less));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn le(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let other = Value::new(&interp, other);
+        let result = Self::compare(&interp, &value, &other)
+            .map(|cmp| interp.convert(cmp != Ordering::Greater));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn gt(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let other = Value::new(&interp, other);
+        let result = Self::compare(&interp, &value, &other)
+            .map(|cmp| interp.convert(cmp == Ordering::Greater));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn ge(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let other = Value::new(&interp, other);
+        let result = Self::compare(&interp, &value, &other)
+            .map(|cmp| interp.convert(cmp != Ordering::Less));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn between(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let (min, max) = mrb_get_args!(mrb, required = 2);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let min = Value::new(&interp, min);
+        let max = Value::new(&interp, max);
+        let result = Self::compare(&interp, &value, &min)
+            .and_then(|lower| {
+                if lower == Ordering::Less {
+                    Ok(false)
+                } else {
+                    Self::compare(&interp, &value, &max).map(|upper| upper != Ordering::Greater)
+                }
+            })
+            .map(|between| interp.convert(between));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn clamp(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let (first, second) = mrb_get_args!(mrb, required = 1, optional = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let first = Value::new(&interp, first);
+        let result = if let Some(max) = second {
+            let max = Value::new(&interp, max);
+            Self::clamp_with_bounds(&interp, &value, &first, &max)
+        } else if first.ruby_type() == Ruby::Range {
+            Self::clamp_with_range(&interp, &value, &first)
+        } else {
+            Err(Box::new(TypeError::new(
+                &interp,
+                format!(
+                    "wrong argument type {} (expected Range)",
+                    first.pretty_name()
+                ),
+            )) as Box<dyn RubyException>)
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+
+    use crate::ArtichokeError;
+
+    #[test]
+    fn between() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"3.between?(1, 5)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"6.between?(1, 5)").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"'cat'.between?('ant', 'dog')").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn clamp_with_bounds() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"12.clamp(6, 10)").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 10);
+        let result = interp.eval(b"4.clamp(6, 10)").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 6);
+        let result = interp.eval(b"8.clamp(6, 10)").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 8);
+    }
+
+    #[test]
+    fn clamp_with_range() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"12.clamp(6..10)").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 10);
+        let result = interp.eval(b"4.clamp(6..10)").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 6);
+        let result = interp.eval(b"8.clamp(6..10)").map(|_| ());
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn clamp_with_reversed_bounds() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"1.clamp(10, 6)").map(|_| ());
+        assert_eq!(
+            result,
+            Err(ArtichokeError::Exec(
+                "ArgumentError: min argument must be smaller than max argument".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn failed_comparison() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"'a' < 1").map(|_| ());
+        assert_eq!(
+            result,
+            Err(ArtichokeError::Exec(
+                "ArgumentError: comparison of String with 1 failed".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn mixin() {
+        let interp = crate::interpreter().expect("init");
+        interp
+            .eval(
+                br#"
+                class Pair
+                  include Comparable
+
+                  attr_reader :weight
+
+                  def initialize(weight)
+                    @weight = weight
+                  end
+
+                  def <=>(other)
+                    weight <=> other.weight
+                  end
+                end
+                "#,
+            )
+            .expect("eval");
+        let result = interp.eval(b"Pair.new(1) < Pair.new(2)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"Pair.new(3).clamp(Pair.new(1), Pair.new(2)).weight");
+        assert_eq!(
+            result.and_then(|value| value.try_into::<i64>()),
+            Ok(2),
+            "clamp works with user-defined Comparable classes"
+        );
+    }
+}
@@ -22,12 +22,26 @@ module Enumerable
 end
 
 class Enumerator
+  # Internal helper for the native +Enumerator::Lazy+ pipeline in +lazy.rs+.
+  # Boxes the next element in a one-element Array and signals exhaustion with
+  # +nil+, so the native driver can detect the end of iteration by rescuing
+  # +StopIteration+ here instead of matching rendered error messages.
+  def __lazy_next
+    [self.next]
+  rescue StopIteration
+    nil
+  end
+
   # == Acknowledgements
   #
   #   Based on https://github.com/yhara/enumerable-lazy
   #   Inspired by https://github.com/antimon2/enumerable_lz
   #   http://jp.rubyist.net/magazine/?0034-Enumerable_lz (ja)
   class Lazy < Enumerator
+    # The native implementation of Lazy#each needs the Enumerator behavior for
+    # yielder-based instances created by Lazy.new.
+    alias __enumerator_each each
+
     def initialize(obj, &block)
       super() do |yielder|
         obj.each do |x|
@@ -53,25 +67,8 @@ class Enumerator
     end
     alias enum_for to_enum
 
-    def map(&block)
-      Lazy.new(self) do |yielder, val|
-        yielder << block.call(val)
-      end
-    end
-    alias collect map
-
-    def select(&block)
-      Lazy.new(self) do |yielder, val|
-        yielder << val if block.call(val)
-      end
-    end
-    alias find_all select
-
-    def reject(&block)
-      Lazy.new(self) do |yielder, val|
-        yielder << val unless block.call(val)
-      end
-    end
+    # map/collect, select/find_all, and reject are implemented natively in
+    # lazy.rs and registered after this file is evaluated.
 
     def grep(pattern)
       Lazy.new(self) do |yielder, val|
@@ -164,3 +161,16 @@ class Enumerator
     alias force to_a
   end
 end
+
+class Proc
+  # Internal helper for the native +Enumerator::Lazy+ pipeline in +lazy.rs+.
+  # Calls the proc and reports whether iteration should continue; a
+  # +StopIteration+ raised by a downstream yielder chain -- +Lazy#take+ for
+  # example -- stops iteration without tearing down the pipeline.
+  def __lazy_call(*args)
+    call(*args)
+    true
+  rescue StopIteration
+    false
+  end
+end
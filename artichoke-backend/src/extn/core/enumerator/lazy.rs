@@ -0,0 +1,497 @@
+//! Native implementations of the hot `Enumerator::Lazy` combinators.
+//!
+//! The pure-Ruby implementation in `lazy.rb` builds a chain of yielder blocks
+//! which allocates several Ruby objects per element per step. The native
+//! implementations below instead record the combinator pipeline as data —
+//! `(operation, block)` pairs — and drive the source enumerator once per
+//! pulled element.
+//!
+//! The pipeline is stored in instance variables on the derived
+//! `Enumerator::Lazy` object so every block and the source enumerator stay
+//! reachable by the garbage collector. Stored blocks are invoked as `Proc`s,
+//! and the end of iteration is detected by rescuing `StopIteration` in the
+//! `__lazy_next` and `__lazy_call` helpers in `lazy.rb` rather than by
+//! matching rendered error messages.
+
+use artichoke_core::value::Value as _;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::ffi::c_void;
+
+use crate::class;
+use crate::convert::Convert;
+use crate::def::EnclosingRubyScope;
+use crate::extn::core::enumerator::Enumerator;
+use crate::extn::core::exception::{
+    self, ArgumentError, Fatal, RubyException, RuntimeError, TypeError,
+};
+use crate::sys;
+use crate::types::Int;
+use crate::value::{Block, Value, ValueLike};
+use crate::{Artichoke, ArtichokeError};
+
+/// Instance variable holding the source enumerable of a derived pipeline.
+const SOURCE_IVAR: &str = "@__lazy_source";
+/// Instance variable holding the `[operation, block]` pairs of a pipeline.
+const OPS_IVAR: &str = "@__lazy_ops";
+
+const OP_MAP: Int = 0;
+const OP_SELECT: Int = 1;
+const OP_REJECT: Int = 2;
+
+/// Register the native `Enumerator::Lazy` combinators.
+///
+/// This must run after `lazy.rb` is evaluated so the native methods override
+/// the pure-Ruby implementations.
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    if interp.0.borrow().class_spec::<Lazy>().is_some() {
+        return Ok(());
+    }
+    let scope = {
+        let borrow = interp.0.borrow();
+        let spec = borrow
+            .class_spec::<Enumerator>()
+            .ok_or(ArtichokeError::NotDefined(Cow::Borrowed("Enumerator")))?;
+        EnclosingRubyScope::class(spec)
+    };
+    let spec = class::Spec::new("Lazy", Some(scope), None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method("map", Lazy::map, sys::mrb_args_block())
+        .add_method("collect", Lazy::map, sys::mrb_args_block())
+        .add_method("select", Lazy::select, sys::mrb_args_block())
+        .add_method("find_all", Lazy::select, sys::mrb_args_block())
+        .add_method("reject", Lazy::reject, sys::mrb_args_block())
+        .add_method("each", Lazy::each, sys::mrb_args_block())
+        .add_method("first", Lazy::first, sys::mrb_args_opt(1))
+        .define()?;
+    interp.0.borrow_mut().def_class::<Lazy>(spec);
+    trace!("Patched Enumerator::Lazy onto interpreter");
+    Ok(())
+}
+
+pub struct Lazy;
+
+impl Lazy {
+    fn source_sym(interp: &Artichoke) -> sys::mrb_sym {
+        interp
+            .0
+            .borrow_mut()
+            .sym_intern(SOURCE_IVAR.as_bytes().to_vec())
+    }
+
+    fn ops_sym(interp: &Artichoke) -> sys::mrb_sym {
+        interp
+            .0
+            .borrow_mut()
+            .sym_intern(OPS_IVAR.as_bytes().to_vec())
+    }
+
+    /// Derive a new pipeline `Lazy` from a receiver by appending an operation.
+    ///
+    /// If the receiver is itself a pipeline, its source and operations are
+    /// reused; otherwise the receiver becomes the source of a fresh pipeline.
+    /// The derived object is allocated with [`sys::mrb_obj_alloc`] so the
+    /// yielder-based `Lazy#initialize` in `lazy.rb` does not run.
+    fn derive(
+        interp: &Artichoke,
+        slf: &Value,
+        op: Int,
+        block: &Block,
+    ) -> Result<Value, Box<dyn RubyException>> {
+        let mrb = interp.0.borrow().mrb;
+        let source_sym = Self::source_sym(interp);
+        let ops_sym = Self::ops_sym(interp);
+        let (source, ops) = {
+            let ops = Value::new(interp, unsafe { sys::mrb_iv_get(mrb, slf.inner(), ops_sym) });
+            if ops.is_nil() {
+                (slf.clone(), unsafe { sys::mrb_ary_new(mrb) })
+            } else {
+                let source = unsafe { sys::mrb_iv_get(mrb, slf.inner(), source_sym) };
+                let ops = ops.funcall::<Value>("dup", &[], None).map_err(|_| {
+                    Box::new(Fatal::new(interp, "Failed to copy lazy enumerator pipeline"))
+                        as Box<dyn RubyException>
+                })?;
+                (Value::new(interp, source), ops.inner())
+            }
+        };
+        let rclass = {
+            let borrow = interp.0.borrow();
+            let spec = borrow.class_spec::<Self>().ok_or_else(|| {
+                Box::new(Fatal::new(interp, "Enumerator::Lazy class spec not registered"))
+                    as Box<dyn RubyException>
+            })?;
+            spec.rclass(interp).ok_or_else(|| {
+                Box::new(Fatal::new(interp, "Enumerator::Lazy class not defined"))
+                    as Box<dyn RubyException>
+            })?
+        };
+        let lazy = unsafe {
+            let obj = sys::mrb_obj_alloc(mrb, sys::mrb_vtype::MRB_TT_OBJECT, rclass);
+            sys::mrb_sys_obj_value(obj as *mut c_void)
+        };
+        unsafe {
+            let pair = sys::mrb_ary_new_capa(mrb, 2);
+            sys::mrb_ary_push(mrb, pair, interp.convert(op).inner());
+            sys::mrb_ary_push(mrb, pair, block.inner());
+            sys::mrb_ary_push(mrb, ops, pair);
+            sys::mrb_iv_set(mrb, lazy, source_sym, source.inner());
+            sys::mrb_iv_set(mrb, lazy, ops_sym, ops);
+        }
+        Ok(Value::new(interp, lazy))
+    }
+
+    /// Extract the source and `(operation, block)` pairs of a receiver.
+    ///
+    /// A receiver without pipeline instance variables — a `Lazy` constructed
+    /// by the Ruby `initialize` — is its own source with an empty pipeline.
+    fn pipeline(
+        interp: &Artichoke,
+        slf: &Value,
+    ) -> Result<(Value, Vec<(Int, Value)>), Box<dyn RubyException>> {
+        let mrb = interp.0.borrow().mrb;
+        let ops = Value::new(interp, unsafe {
+            sys::mrb_iv_get(mrb, slf.inner(), Self::ops_sym(interp))
+        });
+        if ops.is_nil() {
+            return Ok((slf.clone(), vec![]));
+        }
+        let source = Value::new(interp, unsafe {
+            sys::mrb_iv_get(mrb, slf.inner(), Self::source_sym(interp))
+        });
+        let pairs = ops.try_into::<Vec<Value>>().map_err(|_| {
+            Box::new(Fatal::new(interp, "Malformed lazy enumerator pipeline"))
+                as Box<dyn RubyException>
+        })?;
+        let mut pipeline = Vec::with_capacity(pairs.len());
+        for pair in pairs {
+            let pair = pair.try_into::<Vec<Value>>().map_err(|_| {
+                Box::new(Fatal::new(interp, "Malformed lazy enumerator pipeline"))
+                    as Box<dyn RubyException>
+            })?;
+            match (pair.get(0), pair.get(1)) {
+                (Some(op), Some(block)) => {
+                    let op = op.clone().try_into::<Int>().map_err(|_| {
+                        Box::new(Fatal::new(interp, "Malformed lazy enumerator pipeline"))
+                            as Box<dyn RubyException>
+                    })?;
+                    pipeline.push((op, block.clone()));
+                }
+                _ => {
+                    return Err(Box::new(Fatal::new(
+                        interp,
+                        "Malformed lazy enumerator pipeline",
+                    )))
+                }
+            }
+        }
+        Ok((source, pipeline))
+    }
+
+    /// Pull elements from the source, apply the pipeline, and feed surviving
+    /// elements to `collect` until it returns `false` or the source is
+    /// exhausted.
+    ///
+    /// Elements are pulled through a fresh `Enumerator` so infinite sources
+    /// are only iterated as far as `collect` demands.
+    fn drive<F>(interp: &Artichoke, slf: &Value, mut collect: F) -> Result<(), Box<dyn RubyException>>
+    where
+        F: FnMut(&Artichoke, Value) -> Result<bool, Box<dyn RubyException>>,
+    {
+        let (source, pipeline) = Self::pipeline(interp, slf)?;
+        let enumerator = {
+            let borrow = interp.0.borrow();
+            let spec = borrow.class_spec::<Enumerator>().ok_or_else(|| {
+                Box::new(Fatal::new(interp, "Enumerator class spec not registered"))
+                    as Box<dyn RubyException>
+            })?;
+            spec.new_instance(interp, &[source]).ok_or_else(|| {
+                Box::new(Fatal::new(interp, "Failed to construct source Enumerator"))
+                    as Box<dyn RubyException>
+            })?
+        };
+        loop {
+            // `Enumerator#__lazy_next` rescues `StopIteration` and returns
+            // `nil` at the end of iteration. Elements are boxed in a
+            // one-element `Array` so `nil` and `false` elements survive.
+            let item = enumerator
+                .funcall::<Value>("__lazy_next", &[], None)
+                .map_err(|err| raised(interp, &err))?;
+            if item.is_nil() {
+                break;
+            }
+            let mut boxed = item.try_into::<Vec<Value>>().map_err(|_| {
+                Box::new(Fatal::new(
+                    interp,
+                    "Malformed return value from Enumerator#__lazy_next",
+                )) as Box<dyn RubyException>
+            })?;
+            let mut item = boxed.pop().ok_or_else(|| {
+                Box::new(Fatal::new(
+                    interp,
+                    "Malformed return value from Enumerator#__lazy_next",
+                )) as Box<dyn RubyException>
+            })?;
+            let mut keep = true;
+            for (op, block) in &pipeline {
+                let result = block
+                    .funcall::<Value>("call", &[item.clone()], None)
+                    .map_err(|err| raised(interp, &err))?;
+                match *op {
+                    OP_MAP => item = result,
+                    OP_SELECT => keep = Self::is_truthy(interp, &result)?,
+                    OP_REJECT => keep = !Self::is_truthy(interp, &result)?,
+                    _ => {
+                        return Err(Box::new(Fatal::new(
+                            interp,
+                            "Malformed lazy enumerator pipeline",
+                        )))
+                    }
+                }
+                if !keep {
+                    break;
+                }
+            }
+            if keep && !collect(interp, item)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_truthy(interp: &Artichoke, value: &Value) -> Result<bool, Box<dyn RubyException>> {
+        value
+            .funcall::<bool>("!", &[], None)
+            .map(|negated| !negated)
+            .map_err(|err| raised(interp, &err))
+    }
+
+    pub unsafe extern "C" fn map(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = if let Some(block) = block {
+            Self::derive(&interp, &value, OP_MAP, &block)
+        } else {
+            Err(Box::new(ArgumentError::new(
+                &interp,
+                "tried to call lazy map without a block",
+            )) as Box<dyn RubyException>)
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn select(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = if let Some(block) = block {
+            Self::derive(&interp, &value, OP_SELECT, &block)
+        } else {
+            Err(Box::new(ArgumentError::new(
+                &interp,
+                "tried to call lazy select without a block",
+            )) as Box<dyn RubyException>)
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn reject(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = if let Some(block) = block {
+            Self::derive(&interp, &value, OP_REJECT, &block)
+        } else {
+            Err(Box::new(ArgumentError::new(
+                &interp,
+                "tried to call lazy reject without a block",
+            )) as Box<dyn RubyException>)
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn each(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = if let Some(block) = block {
+            let block = Value::new(&interp, block.inner());
+            let ops = Value::new(&interp, sys::mrb_iv_get(mrb, slf, Self::ops_sym(&interp)));
+            if ops.is_nil() {
+                // Yielder-based receivers keep the `Enumerator#each` behavior,
+                // preserved under an alias before the native override.
+                value
+                    .funcall::<Value>("__enumerator_each", &[], Some(block))
+                    .map_err(|err| raised(&interp, &err))
+            } else {
+                Self::drive(&interp, &value, |interp, item| {
+                    // `Proc#__lazy_call` rescues `StopIteration` raised by a
+                    // downstream yielder chain — `Lazy#take` for example —
+                    // and reports whether iteration should continue.
+                    block
+                        .funcall::<bool>("__lazy_call", &[item], None)
+                        .map_err(|err| raised(interp, &err))
+                })
+                .map(|_| value.clone())
+            }
+        } else {
+            Ok(value.clone())
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn first(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let num = mrb_get_args!(mrb, optional = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = if let Some(num) = num {
+            Value::new(&interp, num)
+                .try_into::<Int>()
+                .map_err(|_| {
+                    Box::new(TypeError::new(
+                        &interp,
+                        "no implicit conversion into Integer",
+                    )) as Box<dyn RubyException>
+                })
+                .and_then(|num| {
+                    usize::try_from(num).map_err(|_| {
+                        Box::new(ArgumentError::new(&interp, "attempt to take negative size"))
+                            as Box<dyn RubyException>
+                    })
+                })
+                .and_then(|limit| {
+                    let mut collected = vec![];
+                    if limit > 0 {
+                        Self::drive(&interp, &value, |_, item| {
+                            collected.push(item);
+                            Ok(collected.len() < limit)
+                        })?;
+                    }
+                    Ok(interp.convert(collected))
+                })
+        } else {
+            let mut first = None;
+            Self::drive(&interp, &value, |_, item| {
+                first = Some(item);
+                Ok(false)
+            })
+            .map(|_| interp.convert(first))
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+}
+
+/// Convert a funcall error back into a raisable exception.
+///
+/// `StopIteration` is rescued on the Ruby side by the `__lazy_next` and
+/// `__lazy_call` helpers; any other error has already been reported by the
+/// VM, so the original message is re-raised as a `RuntimeError`.
+fn raised(interp: &Artichoke, err: &ArtichokeError) -> Box<dyn RubyException> {
+    Box::new(RuntimeError::new(interp, err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::types::Int;
+    use crate::value::ValueLike;
+
+    #[test]
+    fn lazy_select_first_on_infinite_range() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"(1..Float::INFINITY).lazy.select { |x| x.odd? }.first(5)")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<Int>>().expect("convert"),
+            vec![1, 3, 5, 7, 9]
+        );
+    }
+
+    #[test]
+    fn lazy_map_reject_chain() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"(1..Float::INFINITY).lazy.map { |x| x * 2 }.reject { |x| x % 3 == 0 }.first(4)")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<Int>>().expect("convert"),
+            vec![2, 4, 8, 10]
+        );
+    }
+
+    #[test]
+    fn lazy_propagates_user_exception_with_stop_iteration_prefix() {
+        let interp = crate::interpreter().expect("init");
+        interp
+            .eval(b"class StopIterationLimit < StandardError; end")
+            .expect("eval");
+        // A user exception whose class name merely begins with
+        // `StopIteration` must propagate instead of silently terminating the
+        // chain with truncated results.
+        let result = interp
+            .eval(b"(1..10).lazy.map { |x| raise StopIterationLimit if x > 2; x }.first(5)")
+            .map(|_| ());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn lazy_first_without_argument() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"(1..Float::INFINITY).lazy.map { |x| x + 1 }.first")
+            .expect("eval");
+        assert_eq!(result.try_into::<Int>().expect("convert"), 2);
+        let result = interp.eval(b"[].lazy.map { |x| x }.first").expect("eval");
+        assert!(result.is_nil());
+    }
+
+    #[test]
+    fn lazy_force_drains_finite_chain() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"(1..6).lazy.select(&:even?).map { |x| x * x }.force")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<Int>>().expect("convert"),
+            vec![4, 16, 36]
+        );
+    }
+
+    #[test]
+    fn ruby_combinators_still_chain_with_native_pipeline() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"(1..Float::INFINITY).lazy.map { |x| x * 2 }.take(3).to_a")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<Int>>().expect("convert"),
+            vec![2, 4, 6]
+        );
+    }
+}
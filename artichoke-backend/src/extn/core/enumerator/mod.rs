@@ -3,6 +3,8 @@ use artichoke_core::eval::Eval;
 use crate::class;
 use crate::{Artichoke, ArtichokeError};
 
+pub mod lazy;
+
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     if interp.0.borrow().class_spec::<Enumerator>().is_some() {
         return Ok(());
@@ -11,8 +13,8 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     interp.0.borrow_mut().def_class::<Enumerator>(spec);
     interp.eval(&include_bytes!("enumerator.rb")[..])?;
     interp.eval(&include_bytes!("lazy.rb")[..])?;
+    lazy::init(interp)?;
     trace!("Patched Enumerator onto interpreter");
-    trace!("Patched Enumerator::Lazy onto interpreter");
     Ok(())
 }
 
@@ -8,6 +8,7 @@
 //!   - `LoadError`
 //!   - `NotImplementedError`
 //!   - `SyntaxError`
+//!   - `InstructionLimitExceeded` -- Artichoke-specific
 //! - `SecurityError`
 //! - `SignalException`
 //!   - `Interrupt`
@@ -45,9 +46,13 @@ use std::borrow::Cow;
 use std::error;
 use std::fmt;
 
+use artichoke_core::value::Value as _;
+
 use crate::class;
 use crate::convert::Convert;
 use crate::sys;
+use crate::types::Ruby;
+use crate::value::Value;
 use crate::{Artichoke, ArtichokeError};
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
@@ -56,6 +61,21 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     let exception_spec = class::Spec::new("Exception", None, None);
     class::Builder::for_spec(interp, &exception_spec)
         .with_super_class(None)
+        .add_method(
+            "backtrace",
+            artichoke_exception_backtrace,
+            sys::mrb_args_none(),
+        )
+        .add_method(
+            "inspect",
+            artichoke_exception_inspect,
+            sys::mrb_args_none(),
+        )
+        .add_method(
+            "set_backtrace",
+            artichoke_exception_set_backtrace,
+            sys::mrb_args_req(1),
+        )
         .define()?;
 
     let nomemory_spec = class::Spec::new("NoMemoryError", None, None);
@@ -83,6 +103,13 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         .with_super_class(Some(&script_spec))
         .define()?;
 
+    // Artichoke-specific `ScriptError` subclass raised by the VM instruction
+    // limit hook. See `Artichoke::set_instruction_limit`.
+    let instructionlimit_spec = class::Spec::new("InstructionLimitExceeded", None, None);
+    class::Builder::for_spec(interp, &instructionlimit_spec)
+        .with_super_class(Some(&script_spec))
+        .define()?;
+
     let security_spec = class::Spec::new("SecurityError", None, None);
     class::Builder::for_spec(interp, &security_spec)
         .with_super_class(Some(&exception_spec))
@@ -233,6 +260,7 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     borrow.def_class::<LoadError>(load_spec);
     borrow.def_class::<NotImplementedError>(notimplemented_spec);
     borrow.def_class::<SyntaxError>(syntax_spec);
+    borrow.def_class::<InstructionLimitExceeded>(instructionlimit_spec);
     borrow.def_class::<SecurityError>(security_spec);
     borrow.def_class::<SignalException>(signal_spec);
     borrow.def_class::<Interrupt>(interrupt_spec);
@@ -269,6 +297,117 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     Ok(())
 }
 
+/// `Exception#inspect` matching the MRI format.
+///
+/// ```txt
+/// [2.6.3] > RuntimeError.new('outta bounds').inspect
+/// => "#<RuntimeError: outta bounds>"
+/// ```
+///
+/// mruby's C implementation formats exceptions like a backtrace frame. This
+/// method is registered on `Exception` so every exception class shares the
+/// MRI-compatible format. [`ExceptionInspect`] produces the same format on
+/// the Rust side.
+#[no_mangle]
+unsafe extern "C" fn artichoke_exception_inspect(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let exception = Value::new(&interp, slf);
+    let classname = exception.pretty_name().to_owned();
+    let message = exception
+        .funcall::<Value>("message", &[], None)
+        .map(|message| message.to_s())
+        .unwrap_or_default();
+    let inspect = format!("#<{}: {}>", classname, message);
+    interp.convert(inspect).inner()
+}
+
+/// `Exception#backtrace` preferring a backtrace injected with
+/// `Exception#set_backtrace`.
+///
+/// Backtraces set by [`artichoke_exception_set_backtrace`] are stored in the
+/// `@custom_backtrace` instance variable. Exceptions without an injected
+/// backtrace fall back to the backtrace captured by the VM at raise time via
+/// [`sys::mrb_exc_backtrace`].
+#[no_mangle]
+unsafe extern "C" fn artichoke_exception_backtrace(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let sym = interp.0.borrow_mut().sym_intern(&b"@custom_backtrace"[..]);
+    if sys::mrb_iv_defined(mrb, slf, sym) != 0 {
+        sys::mrb_iv_get(mrb, slf, sym)
+    } else {
+        sys::mrb_exc_backtrace(mrb, slf)
+    }
+}
+
+/// `Exception#set_backtrace` accepting `nil`, a `String`, or an `Array` of
+/// `String`s like MRI.
+///
+/// mruby's C implementation only accepts an `Array` of `String`s, but test
+/// frameworks like RSpec call `set_backtrace(nil)` and
+/// `set_backtrace("file.rb:10")` to inject fake backtraces. The normalized
+/// backtrace is stored in the `@custom_backtrace` instance variable, which
+/// [`artichoke_exception_backtrace`] prefers over the backtrace captured at
+/// raise time.
+#[no_mangle]
+unsafe extern "C" fn artichoke_exception_set_backtrace(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let backtrace = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let backtrace = Value::new(&interp, backtrace);
+    let normalized = if backtrace.is_nil() {
+        Some(backtrace.clone())
+    } else if backtrace.ruby_type() == Ruby::String {
+        Some(interp.convert(vec![backtrace.clone()]))
+    } else if let Ok(lines) = backtrace.clone().try_into::<Vec<Value>>() {
+        if lines.iter().all(|line| line.ruby_type() == Ruby::String) {
+            Some(backtrace.clone())
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+    if let Some(normalized) = normalized {
+        let sym = interp.0.borrow_mut().sym_intern(&b"@custom_backtrace"[..]);
+        sys::mrb_iv_set(mrb, slf, sym, normalized.inner());
+        backtrace.inner()
+    } else {
+        let exception = TypeError::new(&interp, "backtrace must be Array of String");
+        raise(interp, exception)
+    }
+}
+
+/// Format a [`RubyException`] like MRI's `Exception#inspect`.
+///
+/// The [`fmt::Display`] impl on `RubyException` types matches `Exception#to_s`
+/// prefixed with the class name (`ClassName: message`). Wrap an exception in
+/// `ExceptionInspect` to render `#<ClassName: message>` instead.
+pub struct ExceptionInspect<'a>(&'a dyn RubyException);
+
+impl<'a> ExceptionInspect<'a> {
+    pub fn new(exception: &'a dyn RubyException) -> Self {
+        Self(exception)
+    }
+}
+
+impl<'a> fmt::Display for ExceptionInspect<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.0.name();
+        let message = String::from_utf8_lossy(self.0.message());
+        write!(f, "#<{}: {}>", classname, message)
+    }
+}
+
 /// Raise implementation for `RubyException` boxed trait objects.
 ///
 /// # Safety
@@ -422,7 +561,19 @@ macro_rules! ruby_exception_impl {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 let classname = self.name();
                 let message = String::from_utf8_lossy(self.message());
-                write!(f, "{} ({})", classname, message)
+                write!(f, "{}: {}", classname, message)
+            }
+        }
+
+        impl PartialEq for $exception {
+            fn eq(&self, other: &Self) -> bool {
+                self.name() == other.name() && self.message() == other.message()
+            }
+        }
+
+        impl PartialEq<Box<dyn RubyException>> for $exception {
+            fn eq(&self, other: &Box<dyn RubyException>) -> bool {
+                self.name() == other.name() && self.message() == other.message()
             }
         }
 
@@ -464,7 +615,13 @@ impl fmt::Display for Box<dyn RubyException> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let classname = self.name();
         let message = String::from_utf8_lossy(self.message());
-        write!(f, "{} ({})", classname, message)
+        write!(f, "{}: {}", classname, message)
+    }
+}
+
+impl PartialEq for Box<dyn RubyException> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.message() == other.message()
     }
 }
 
@@ -484,6 +641,7 @@ ruby_exception_impl!(ScriptError);
 ruby_exception_impl!(LoadError);
 ruby_exception_impl!(NotImplementedError);
 ruby_exception_impl!(SyntaxError);
+ruby_exception_impl!(InstructionLimitExceeded);
 ruby_exception_impl!(SecurityError);
 ruby_exception_impl!(SignalException);
 ruby_exception_impl!(Interrupt);
@@ -521,10 +679,10 @@ ruby_exception_impl!(Fatal);
 mod tests {
     use artichoke_core::eval::Eval;
     use artichoke_core::file::File;
+    use artichoke_core::value::Value as _;
 
     use crate::class;
-    use crate::exception::Exception;
-    use crate::extn::core::exception::RuntimeError;
+    use crate::extn::core::exception::{ArgumentError, RubyException, RuntimeError};
     use crate::sys;
     use crate::{Artichoke, ArtichokeError};
 
@@ -544,7 +702,7 @@ mod tests {
         fn require(interp: &Artichoke) -> Result<(), ArtichokeError> {
             let spec = class::Spec::new("Run", None, None);
             class::Builder::for_spec(interp, &spec)
-                .add_self_method("run", Self::run, sys::mrb_args_none())
+                .add_class_method("run", Self::run, sys::mrb_args_none())
                 .define()?;
             interp.0.borrow_mut().def_class::<Self>(spec);
             Ok(())
@@ -556,12 +714,142 @@ mod tests {
         let interp = crate::interpreter().expect("init");
         Run::require(&interp).unwrap();
         let value = interp.eval(b"Run.run").map(|_| ());
-        let expected = Exception::new(
-            "RuntimeError",
-            "something went wrong",
-            Some(vec!["(eval):1".to_owned()]),
-            "(eval):1: something went wrong (RuntimeError)",
+        let expected = "(eval):1: something went wrong (RuntimeError)\n(eval):1";
+        assert_eq!(value, Err(ArtichokeError::Exec(expected.to_owned())));
+    }
+
+    #[test]
+    fn exceptions_compare_by_class_and_message() {
+        let interp = crate::interpreter().expect("init");
+        let exc: Box<dyn RubyException> = Box::new(RuntimeError::new(&interp, "oops"));
+        let same: Box<dyn RubyException> = Box::new(RuntimeError::new(&interp, "oops"));
+        let other_message: Box<dyn RubyException> = Box::new(RuntimeError::new(&interp, "nope"));
+        let other_class: Box<dyn RubyException> = Box::new(ArgumentError::new(&interp, "oops"));
+        assert_eq!(&exc, &same);
+        assert_ne!(&exc, &other_message);
+        assert_ne!(&exc, &other_class);
+        // Concrete exceptions compare directly against boxed trait objects.
+        assert_eq!(RuntimeError::new(&interp, "oops"), same);
+    }
+
+    #[test]
+    fn set_backtrace_overrides_captured_backtrace() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(
+                br#"
+err = begin; raise 'boom'; rescue => err; err; end
+err.set_backtrace(['fake.rb:10', 'fake.rb:1'])
+err.backtrace
+                "#,
+            )
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<&str>>().expect("convert"),
+            vec!["fake.rb:10", "fake.rb:1"]
+        );
+    }
+
+    #[test]
+    fn set_backtrace_accepts_string_and_nil() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"err = RuntimeError.new; err.set_backtrace('file.rb:10'); err.backtrace")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<&str>>().expect("convert"),
+            vec!["file.rb:10"]
+        );
+        let result = interp
+            .eval(
+                br#"
+err = begin; raise 'boom'; rescue => err; err; end
+err.set_backtrace(nil)
+err.backtrace.nil?
+                "#,
+            )
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn set_backtrace_rejects_non_string_entries() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(
+                br#"
+err = RuntimeError.new
+begin
+  err.set_backtrace([1, 2, 3])
+rescue TypeError => err
+  err.message
+end
+                "#,
+            )
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            "backtrace must be Array of String"
+        );
+    }
+
+    #[test]
+    fn exception_method_returns_self_or_copy_with_message() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"err = RuntimeError.new('boom'); err.exception.equal?(err)")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(
+                br#"
+err = RuntimeError.new('boom')
+other = err.exception('bang')
+[other.equal?(err), other.class == err.class, other.message]
+                "#,
+            )
+            .expect("eval");
+        let result = result
+            .try_into::<Vec<crate::value::Value>>()
+            .expect("convert");
+        assert!(!result[0].clone().try_into::<bool>().expect("convert"));
+        assert!(result[1].clone().try_into::<bool>().expect("convert"));
+        assert_eq!(
+            result[2].clone().try_into::<String>().expect("convert"),
+            "bang"
+        );
+    }
+
+    #[test]
+    fn inspect_matches_mri_format() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"RuntimeError.new('outta bounds').inspect")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            "#<RuntimeError: outta bounds>"
+        );
+        let result = interp
+            .eval(b"begin; raise ArgumentError, 'bad arg'; rescue => err; err.inspect; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            "#<ArgumentError: bad arg>"
+        );
+    }
+
+    #[test]
+    fn display_and_inspect_formats() {
+        let interp = crate::interpreter().expect("init");
+        let exc = RuntimeError::new(&interp, "something went wrong");
+        assert_eq!(
+            format!("{}", exc),
+            "RuntimeError: something went wrong"
+        );
+        assert_eq!(
+            format!("{}", super::ExceptionInspect::new(&exc)),
+            "#<RuntimeError: something went wrong>"
         );
-        assert_eq!(value, Err(ArtichokeError::Exec(expected.to_string())));
     }
 }
@@ -0,0 +1,77 @@
+use crate::class;
+use crate::{Artichoke, ArtichokeError};
+
+/// `Fiber` is implemented by the vendored mruby-fiber mrbgem, which suspends
+/// and resumes `mrb_context` structs directly in the VM. This is strictly more
+/// capable than a Rust-side coroutine or OS thread implementation because a
+/// suspended fiber shares the interpreter heap with no synchronization.
+///
+/// `init` registers a [`class::Spec`] for the existing class so `Fiber` is
+/// resolvable from Rust with `class_spec::<Fiber>()` like every other core
+/// class. `FiberError` is raised by the mrbgem when resuming a dead fiber or
+/// calling `Fiber.yield` outside of a fiber; the corresponding exception spec
+/// is registered in `extn::core::exception`.
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    if interp.0.borrow().class_spec::<Fiber>().is_some() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Fiber", None, None);
+    interp.0.borrow_mut().def_class::<Fiber>(spec);
+    trace!("Patched Fiber onto interpreter");
+    Ok(())
+}
+
+pub struct Fiber;
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::types::Int;
+    use crate::value::ValueLike;
+    use crate::ArtichokeError;
+
+    #[test]
+    fn fiber_resume_returns_block_value() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"f = Fiber.new { |x| x * 2 }; f.resume(3)")
+            .expect("eval");
+        assert_eq!(result.try_into::<Int>().expect("convert"), 6);
+    }
+
+    #[test]
+    fn fiber_yield_suspends_and_resumes() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"f = Fiber.new { |x| Fiber.yield x + 1 }; f.resume(1)")
+            .expect("eval");
+        assert_eq!(result.try_into::<Int>().expect("convert"), 2);
+    }
+
+    #[test]
+    fn resuming_dead_fiber_raises_fiber_error() {
+        let interp = crate::interpreter().expect("init");
+        let err = interp
+            .eval(b"f = Fiber.new { nil }; f.resume; f.resume")
+            .unwrap_err();
+        match err {
+            ArtichokeError::Exec(message) => {
+                assert!(message.starts_with("FiberError"), "got: {}", message)
+            }
+            err => panic!("expected FiberError, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn yield_outside_fiber_raises_fiber_error() {
+        let interp = crate::interpreter().expect("init");
+        let err = interp.eval(b"Fiber.yield").unwrap_err();
+        match err {
+            ArtichokeError::Exec(message) => {
+                assert!(message.starts_with("FiberError"), "got: {}", message)
+            }
+            err => panic!("expected FiberError, got {:?}", err),
+        }
+    }
+}
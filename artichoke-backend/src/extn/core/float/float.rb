@@ -5,3 +5,37 @@ class Float
     floor
   end
 end
+
+# Arithmetic operators retry through the right operand's #coerce protocol when
+# the native operator rejects the operand with a TypeError. See the matching
+# wrappers in integer.rb.
+class Float
+  alias __add_without_coerce +
+  alias __sub_without_coerce -
+  alias __mul_without_coerce *
+  alias __div_without_coerce /
+
+  def +(other)
+    __add_without_coerce(other)
+  rescue TypeError
+    __coerce_retry(:+, other)
+  end
+
+  def -(other)
+    __sub_without_coerce(other)
+  rescue TypeError
+    __coerce_retry(:-, other)
+  end
+
+  def *(other)
+    __mul_without_coerce(other)
+  rescue TypeError
+    __coerce_retry(:*, other)
+  end
+
+  def /(other)
+    __div_without_coerce(other)
+  rescue TypeError
+    __coerce_retry(:/, other)
+  end
+end
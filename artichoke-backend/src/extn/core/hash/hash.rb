@@ -341,48 +341,6 @@ class Hash
     ->(key) { self[key] }
   end
 
-  def transform_keys(&block)
-    return to_enum :transform_keys unless block
-
-    hash = {}
-    keys.each do |k|
-      new_key = block.call(k)
-      hash[new_key] = self[k]
-    end
-    hash
-  end
-
-  def transform_keys!(&block)
-    return to_enum :transform_keys! unless block
-
-    keys.each do |k|
-      value = self[k]
-      __delete(k)
-      k = block.call(k) if block
-      self[k] = value
-    end
-    self
-  end
-
-  def transform_values
-    return to_enum :transform_values unless block_given?
-
-    hash = {}
-    keys.each do |k|
-      hash[k] = yield(self[k])
-    end
-    hash
-  end
-
-  def transform_values!
-    return to_enum :transform_values! unless block_given?
-
-    keys.each do |k|
-      self[k] = yield(self[k])
-    end
-    self
-  end
-
   alias each_pair each
   alias initialize_copy replace
   alias to_s inspect
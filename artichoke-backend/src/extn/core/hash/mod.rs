@@ -1,6 +1,11 @@
 use artichoke_core::eval::Eval;
+use artichoke_core::value::Value as _;
 
 use crate::class;
+use crate::convert::Convert;
+use crate::extn::core::exception::{self, Fatal, FrozenError, RubyException, RuntimeError};
+use crate::sys;
+use crate::value::{Block, Value};
 use crate::{Artichoke, ArtichokeError};
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
@@ -8,6 +13,26 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         return Ok(());
     }
     let spec = class::Spec::new("Hash", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method("transform_keys", Hash::transform_keys, sys::mrb_args_block())
+        .add_method(
+            "transform_keys!",
+            Hash::transform_keys_bang,
+            sys::mrb_args_block(),
+        )
+        .add_method(
+            "transform_values",
+            Hash::transform_values,
+            sys::mrb_args_block(),
+        )
+        .add_method(
+            "transform_values!",
+            Hash::transform_values_bang,
+            sys::mrb_args_block(),
+        )
+        .add_method("filter_map", Hash::filter_map, sys::mrb_args_block())
+        .add_method("to_a", Hash::to_a, sys::mrb_args_none())
+        .define()?;
     interp.0.borrow_mut().def_class::<Hash>(spec);
     interp.eval(&include_bytes!("hash.rb")[..])?;
     trace!("Patched Hash onto interpreter");
@@ -15,3 +40,324 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 }
 
 pub struct Hash;
+
+impl Hash {
+    unsafe extern "C" fn transform_keys(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = match block {
+            Some(block) => transform_keys(&interp, &value, &block),
+            None => enumerator(&interp, &value, "transform_keys"),
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn transform_keys_bang(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = match block {
+            Some(block) => transform_keys_in_place(&interp, &value, &block),
+            None => enumerator(&interp, &value, "transform_keys!"),
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn transform_values(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = match block {
+            Some(block) => transform_values(&interp, &value, &block),
+            None => enumerator(&interp, &value, "transform_values"),
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn transform_values_bang(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = match block {
+            Some(block) => transform_values_in_place(&interp, &value, &block),
+            None => enumerator(&interp, &value, "transform_values!"),
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn filter_map(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let block = mrb_get_args!(mrb, &block);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = match block {
+            Some(block) => filter_map(&interp, &value, &block),
+            None => enumerator(&interp, &value, "filter_map"),
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn to_a(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = to_a(&interp, &value);
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+}
+
+/// Return an `Enumerator` for a blockless call to an iteration method.
+fn enumerator(
+    interp: &Artichoke,
+    hash: &Value,
+    method: &str,
+) -> Result<Value, Box<dyn RubyException>> {
+    hash.funcall::<Value>("to_enum", &[interp.convert(method)], None)
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(interp, "Unable to create Enumerator"))
+        })
+}
+
+/// Extract a `Hash`'s keys in insertion order.
+fn keys(interp: &Artichoke, hash: &Value) -> Result<Vec<Value>, Box<dyn RubyException>> {
+    let mrb = interp.0.borrow().mrb;
+    let keys = unsafe { sys::mrb_hash_keys(mrb, hash.inner()) };
+    Value::new(interp, keys)
+        .try_into::<Vec<Value>>()
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(interp, "Unable to extract Hash keys"))
+        })
+}
+
+/// Raise `FrozenError` if a `Hash` receiving a mutating method is frozen.
+fn check_not_frozen(interp: &Artichoke, hash: &Value) -> Result<(), Box<dyn RubyException>> {
+    if hash.is_frozen() {
+        Err(Box::new(FrozenError::new(
+            interp,
+            "can't modify frozen Hash",
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn transform_keys(
+    interp: &Artichoke,
+    hash: &Value,
+    block: &Block,
+) -> Result<Value, Box<dyn RubyException>> {
+    let mrb = interp.0.borrow().mrb;
+    let result = unsafe { sys::mrb_hash_new(mrb) };
+    for key in keys(interp, hash)? {
+        let value = unsafe { sys::mrb_hash_get(mrb, hash.inner(), key.inner()) };
+        let new_key = block.yield_arg(interp, &key).map_err(|_| {
+            RuntimeError::new(interp, "exception during Hash#transform_keys block")
+        })?;
+        unsafe { sys::mrb_hash_set(mrb, result, new_key.inner(), value) };
+    }
+    Ok(Value::new(interp, result))
+}
+
+fn transform_keys_in_place(
+    interp: &Artichoke,
+    hash: &Value,
+    block: &Block,
+) -> Result<Value, Box<dyn RubyException>> {
+    check_not_frozen(interp, hash)?;
+    let mrb = interp.0.borrow().mrb;
+    for key in keys(interp, hash)? {
+        let value = unsafe { sys::mrb_hash_delete_key(mrb, hash.inner(), key.inner()) };
+        let new_key = block.yield_arg(interp, &key).map_err(|_| {
+            RuntimeError::new(interp, "exception during Hash#transform_keys! block")
+        })?;
+        unsafe { sys::mrb_hash_set(mrb, hash.inner(), new_key.inner(), value) };
+    }
+    Ok(hash.clone())
+}
+
+fn transform_values(
+    interp: &Artichoke,
+    hash: &Value,
+    block: &Block,
+) -> Result<Value, Box<dyn RubyException>> {
+    let mrb = interp.0.borrow().mrb;
+    let result = unsafe { sys::mrb_hash_new(mrb) };
+    for key in keys(interp, hash)? {
+        let value = unsafe { sys::mrb_hash_get(mrb, hash.inner(), key.inner()) };
+        let new_value = block
+            .yield_arg(interp, &Value::new(interp, value))
+            .map_err(|_| {
+                RuntimeError::new(interp, "exception during Hash#transform_values block")
+            })?;
+        unsafe { sys::mrb_hash_set(mrb, result, key.inner(), new_value.inner()) };
+    }
+    Ok(Value::new(interp, result))
+}
+
+fn transform_values_in_place(
+    interp: &Artichoke,
+    hash: &Value,
+    block: &Block,
+) -> Result<Value, Box<dyn RubyException>> {
+    check_not_frozen(interp, hash)?;
+    let mrb = interp.0.borrow().mrb;
+    for key in keys(interp, hash)? {
+        let value = unsafe { sys::mrb_hash_get(mrb, hash.inner(), key.inner()) };
+        let new_value = block
+            .yield_arg(interp, &Value::new(interp, value))
+            .map_err(|_| {
+                RuntimeError::new(interp, "exception during Hash#transform_values! block")
+            })?;
+        unsafe { sys::mrb_hash_set(mrb, hash.inner(), key.inner(), new_value.inner()) };
+    }
+    Ok(hash.clone())
+}
+
+fn filter_map(
+    interp: &Artichoke,
+    hash: &Value,
+    block: &Block,
+) -> Result<Value, Box<dyn RubyException>> {
+    let mrb = interp.0.borrow().mrb;
+    let mut items = Vec::new();
+    for key in keys(interp, hash)? {
+        let value = unsafe { sys::mrb_hash_get(mrb, hash.inner(), key.inner()) };
+        let pair = interp.convert(vec![key, Value::new(interp, value)]);
+        let result = block
+            .yield_arg(interp, &pair)
+            .map_err(|_| RuntimeError::new(interp, "exception during Hash#filter_map block"))?;
+        // `filter_map` keeps the block's result itself when it is truthy.
+        if result.clone().try_into::<bool>().unwrap_or_default() {
+            items.push(result);
+        }
+    }
+    Ok(interp.convert(items))
+}
+
+fn to_a(interp: &Artichoke, hash: &Value) -> Result<Value, Box<dyn RubyException>> {
+    let mrb = interp.0.borrow().mrb;
+    let mut pairs = Vec::new();
+    for key in keys(interp, hash)? {
+        let value = unsafe { sys::mrb_hash_get(mrb, hash.inner(), key.inner()) };
+        pairs.push(interp.convert(vec![key, Value::new(interp, value)]));
+    }
+    Ok(interp.convert(pairs))
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+
+    #[test]
+    fn transform_values_returns_new_hash() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"{a: 1, b: 2}.transform_values { |v| v * 2 } == {a: 2, b: 4}")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"h = {a: 1}; h.transform_values { |v| v * 2 }; h == {a: 1}")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn transform_keys_replaces_keys() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"{a: 1, b: 2}.transform_keys(&:to_s) == {'a' => 1, 'b' => 2}")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"h = {a: 1}; h.transform_keys!(&:to_s); h == {'a' => 1}")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn bang_variants_raise_frozen_error_on_frozen_hash() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(
+                b"begin; {a: 1}.freeze.transform_keys!(&:to_s); rescue FrozenError; :frozen; end",
+            )
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("frozen")
+        );
+        let result = interp
+            .eval(
+                b"begin; {a: 1}.freeze.transform_values! { |v| v }; rescue FrozenError; :frozen; end",
+            )
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("frozen")
+        );
+    }
+
+    #[test]
+    fn filter_map_keeps_truthy_block_results() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"{a: 1, b: 2, c: 3}.filter_map { |k, v| k if v.odd? }")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<String>>().expect("convert"),
+            vec![String::from("a"), String::from("c")]
+        );
+    }
+
+    #[test]
+    fn to_a_returns_pairs() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"{a: 1, b: 2}.to_a == [[:a, 1], [:b, 2]]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn blockless_calls_return_enumerator() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"{a: 1}.transform_values.is_a?(Enumerator)")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+}
@@ -49,3 +49,39 @@ class Integer
   alias round floor
   alias truncate floor
 end
+
+# Arithmetic operators retry through the right operand's #coerce protocol when
+# the native operator rejects the operand with a TypeError. The aliases
+# preserve the C implementations; the VM's arithmetic fast paths do not
+# dispatch for Fixnum/Float operand pairs, so these wrappers only run for
+# mixed operand types.
+class Fixnum
+  alias __add_without_coerce +
+  alias __sub_without_coerce -
+  alias __mul_without_coerce *
+  alias __div_without_coerce /
+
+  def +(other)
+    __add_without_coerce(other)
+  rescue TypeError
+    __coerce_retry(:+, other)
+  end
+
+  def -(other)
+    __sub_without_coerce(other)
+  rescue TypeError
+    __coerce_retry(:-, other)
+  end
+
+  def *(other)
+    __mul_without_coerce(other)
+  rescue TypeError
+    __coerce_retry(:*, other)
+  end
+
+  def /(other)
+    __div_without_coerce(other)
+  rescue TypeError
+    __coerce_retry(:/, other)
+  end
+end
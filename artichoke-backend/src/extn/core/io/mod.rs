@@ -0,0 +1,196 @@
+use artichoke_core::value::Value as _;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::io::{self, Write};
+
+use crate::class;
+use crate::convert::Convert;
+use crate::sys;
+use crate::types::Int;
+use crate::value::{Value, ValueLike};
+use crate::{Artichoke, ArtichokeError};
+
+const STDOUT_FD: Int = 1;
+const STDERR_FD: Int = 2;
+
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    if interp.0.borrow().class_spec::<IO>().is_some() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("IO", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method("puts", IO::puts, sys::mrb_args_rest())
+        .add_method("print", IO::print, sys::mrb_args_rest())
+        .add_method("write", IO::write, sys::mrb_args_rest())
+        .add_method("flush", IO::flush, sys::mrb_args_none())
+        .define()?;
+    interp.0.borrow_mut().def_class::<IO>(spec);
+    // Bind `$stdout` and `$stderr` to Rust-backed `IO` instances so all Ruby
+    // output is routed through `State::print` and participates in
+    // `State::capture_output`.
+    let (stdout, stderr) = {
+        let borrow = interp.0.borrow();
+        let spec = borrow
+            .class_spec::<IO>()
+            .ok_or(ArtichokeError::NotDefined(Cow::Borrowed("IO")))?;
+        let stdout = spec
+            .new_instance(interp, &[])
+            .ok_or(ArtichokeError::New)?;
+        let stderr = spec
+            .new_instance(interp, &[])
+            .ok_or(ArtichokeError::New)?;
+        (stdout, stderr)
+    };
+    let mrb = interp.0.borrow().mrb;
+    let fd_sym = interp.0.borrow_mut().sym_intern(&b"@fd"[..]);
+    let stdout_sym = interp.0.borrow_mut().sym_intern(&b"$stdout"[..]);
+    let stderr_sym = interp.0.borrow_mut().sym_intern(&b"$stderr"[..]);
+    unsafe {
+        sys::mrb_iv_set(mrb, stdout.inner(), fd_sym, interp.convert(STDOUT_FD).inner());
+        sys::mrb_iv_set(mrb, stderr.inner(), fd_sym, interp.convert(STDERR_FD).inner());
+        sys::mrb_gv_set(mrb, stdout_sym, stdout.inner());
+        sys::mrb_gv_set(mrb, stderr_sym, stderr.inner());
+    }
+    trace!("Patched IO onto interpreter");
+    Ok(())
+}
+
+pub struct IO;
+
+impl IO {
+    /// The file descriptor an `IO` instance was bound to at `init`.
+    fn fd(interp: &Artichoke, slf: sys::mrb_value) -> Int {
+        let mrb = interp.0.borrow().mrb;
+        let sym = interp.0.borrow_mut().sym_intern(&b"@fd"[..]);
+        let fd = unsafe { sys::mrb_iv_get(mrb, slf, sym) };
+        Value::new(interp, fd)
+            .try_into::<Int>()
+            .unwrap_or(STDOUT_FD)
+    }
+
+    /// Write a chunk to the stream this instance is bound to.
+    ///
+    /// Stdout goes through `State::print` so it is captured by
+    /// `State::capture_output`. Stderr writes to the process stderr; captured
+    /// stderr is a separate facility that `State` does not provide yet.
+    fn write_str(interp: &Artichoke, fd: Int, s: &str) {
+        if fd == STDERR_FD {
+            eprint!("{}", s);
+            let _ = io::stderr().flush();
+        } else {
+            interp.0.borrow_mut().print(s);
+        }
+    }
+
+    fn puts_str(interp: &Artichoke, fd: Int, s: &str) {
+        if fd == STDERR_FD {
+            eprintln!("{}", s);
+        } else {
+            interp.0.borrow_mut().puts(s);
+        }
+    }
+
+    unsafe extern "C" fn print(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let args = mrb_get_args!(mrb, *args);
+        let interp = unwrap_interpreter!(mrb);
+        let fd = Self::fd(&interp, slf);
+        for value in args.iter() {
+            let s = Value::new(&interp, *value).to_s();
+            Self::write_str(&interp, fd, s.as_str());
+        }
+        sys::mrb_sys_nil_value()
+    }
+
+    unsafe extern "C" fn puts(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        fn do_puts(interp: &Artichoke, fd: Int, value: &Value) {
+            if let Ok(array) = value.clone().try_into::<Vec<Value>>() {
+                for value in array {
+                    do_puts(interp, fd, &value);
+                }
+            } else {
+                let s = value.to_s();
+                IO::puts_str(interp, fd, s.as_str());
+            }
+        }
+
+        let args = mrb_get_args!(mrb, *args);
+        let interp = unwrap_interpreter!(mrb);
+        let fd = Self::fd(&interp, slf);
+        if args.is_empty() {
+            Self::puts_str(&interp, fd, "");
+        }
+        for value in args.iter() {
+            do_puts(&interp, fd, &Value::new(&interp, *value));
+        }
+        sys::mrb_sys_nil_value()
+    }
+
+    unsafe extern "C" fn write(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let args = mrb_get_args!(mrb, *args);
+        let interp = unwrap_interpreter!(mrb);
+        let fd = Self::fd(&interp, slf);
+        let mut bytes = 0_usize;
+        for value in args.iter() {
+            let s = Value::new(&interp, *value).to_s();
+            bytes += s.len();
+            Self::write_str(&interp, fd, s.as_str());
+        }
+        let bytes = Int::try_from(bytes).unwrap_or_default();
+        interp.convert(bytes).inner()
+    }
+
+    unsafe extern "C" fn flush(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        if Self::fd(&interp, slf) == STDERR_FD {
+            let _ = io::stderr().flush();
+        } else {
+            let _ = io::stdout().flush();
+        }
+        slf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::types::Int;
+    use crate::value::ValueLike;
+
+    #[test]
+    fn stdout_global_routes_through_capture() {
+        let interp = crate::interpreter().expect("init");
+        interp.0.borrow_mut().capture_output();
+        let _ = interp.eval(b"$stdout.print 'a', 'b'").expect("eval");
+        let _ = interp.eval(b"$stdout.puts 'c'").expect("eval");
+        assert_eq!(
+            interp.0.borrow_mut().get_and_clear_captured_output(),
+            "abc\n"
+        );
+    }
+
+    #[test]
+    fn write_returns_byte_count() {
+        let interp = crate::interpreter().expect("init");
+        interp.0.borrow_mut().capture_output();
+        let result = interp.eval(b"$stdout.write('artichoke')").expect("eval");
+        assert_eq!(result.try_into::<Int>().expect("convert"), 9);
+    }
+
+    #[test]
+    fn stderr_is_a_distinct_io_instance() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"$stderr.is_a?(IO)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"$stderr.equal?($stdout)").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn flush_returns_self() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"$stdout.flush.equal?($stdout)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+}
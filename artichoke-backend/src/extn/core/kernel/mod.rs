@@ -1,6 +1,8 @@
 use artichoke_core::eval::Eval;
 use artichoke_core::value::Value as _;
+use artichoke_core::warn::Warn;
 
+use crate::convert::Convert;
 use crate::def::EnclosingRubyScope;
 use crate::extn::core::artichoke;
 use crate::extn::core::exception;
@@ -18,6 +20,7 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     }
     let spec = module::Spec::new("Kernel", None);
     module::Builder::for_spec(interp, &spec)
+        .add_method("__dir__", Kernel::dir, sys::mrb_args_none())
         .add_method("require", Kernel::require, sys::mrb_args_rest())
         .add_method(
             "require_relative",
@@ -27,6 +30,7 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         .add_method("load", Kernel::load, sys::mrb_args_rest())
         .add_method("print", Kernel::print, sys::mrb_args_rest())
         .add_method("puts", Kernel::puts, sys::mrb_args_rest())
+        .add_method("warn", Kernel::warn, sys::mrb_args_rest())
         .define()?;
     interp.0.borrow_mut().def_module::<Kernel>(spec);
     interp.eval(&include_bytes!("kernel.rb")[..])?;
@@ -50,6 +54,33 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 pub struct Kernel;
 
 impl Kernel {
+    unsafe extern "C" fn dir(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        // `__dir__` is the directory of the file given by the `__FILE__` magic
+        // constant, which Artichoke tracks with the eval context stack. Code
+        // evaled on the root "(eval)" context has no file, so `__dir__` is
+        // `nil` there, as in MRI.
+        let filename = {
+            let api = interp.0.borrow();
+            api.context_stack
+                .last()
+                .map(|context| context.filename.clone().into_owned())
+        };
+        let filename = match filename {
+            Some(ref filename) if filename.as_slice() != Artichoke::TOP_FILENAME => {
+                String::from_utf8_lossy(filename).into_owned()
+            }
+            _ => return sys::mrb_sys_nil_value(),
+        };
+        let dirname = match filename.rfind('/') {
+            Some(0) => "/",
+            Some(index) => &filename[..index],
+            None => ".",
+        };
+        interp.convert(dirname).inner()
+    }
+
     unsafe extern "C" fn integer(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
         let (arg, base) = mrb_get_args!(mrb, required = 1, optional = 1);
         let interp = unwrap_interpreter!(mrb);
@@ -109,6 +140,17 @@ impl Kernel {
         sys::mrb_sys_nil_value()
     }
 
+    unsafe extern "C" fn warn(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let args = mrb_get_args!(mrb, *args);
+        let interp = unwrap_interpreter!(mrb);
+
+        for value in args.iter() {
+            let message = Value::new(&interp, *value).to_s();
+            let _ = Warn::warn(&interp, message.as_bytes());
+        }
+        sys::mrb_sys_nil_value()
+    }
+
     unsafe extern "C" fn require(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
         let file = mrb_get_args!(mrb, required = 1);
         let interp = unwrap_interpreter!(mrb);
@@ -181,16 +223,45 @@ mod tests {
         let second_i_result = result.try_into::<i64>();
         assert_eq!(second_i_result, Ok(1000));
         let result = interp.eval(b"require 'non-existent-source'").map(|_| ());
-        let expected = r#"
-(eval):1: cannot load such file -- non-existent-source (LoadError)
-(eval):1
-            "#;
         assert_eq!(
             result,
-            Err(ArtichokeError::Exec(expected.trim().to_owned()))
+            Err(ArtichokeError::LoadError {
+                path: String::from("non-existent-source")
+            })
         );
     }
 
+    #[test]
+    fn require_chain_deeper_than_context_limit_raises_system_stack_error() {
+        // Each file in the chain pushes an eval context, so a chain longer
+        // than the default max context depth of 500 must trip the limit.
+        // Run on a thread with a generous stack so the limit, not the OS
+        // stack, is what stops the recursion.
+        let child = std::thread::Builder::new()
+            .stack_size(16 * 1024 * 1024)
+            .spawn(|| {
+                let interp = crate::interpreter().expect("init");
+                for i in 0..600 {
+                    let path = format!("chain{}.rb", i);
+                    let contents = format!("require 'chain{}'", i + 1);
+                    interp
+                        .def_rb_source_file(path.as_bytes(), contents.into_bytes())
+                        .expect("def file");
+                }
+                let result = interp
+                    .eval(
+                        b"begin; require 'chain0'; :no_exception; rescue SystemStackError; :exception; end",
+                    )
+                    .expect("eval");
+                assert_eq!(
+                    result.try_into::<String>().expect("convert"),
+                    String::from("exception")
+                );
+            })
+            .expect("spawn");
+        child.join().expect("join");
+    }
+
     #[test]
     fn require_absolute_path() {
         let interp = crate::interpreter().expect("init");
@@ -220,13 +291,11 @@ mod tests {
     fn require_directory() {
         let interp = crate::interpreter().expect("init");
         let result = interp.eval(b"require '/src'").map(|_| ());
-        let expected = r#"
-(eval):1: cannot load such file -- /src (LoadError)
-(eval):1
-        "#;
         assert_eq!(
             result,
-            Err(ArtichokeError::Exec(expected.trim().to_owned()))
+            Err(ArtichokeError::LoadError {
+                path: String::from("/src")
+            })
         );
     }
 
@@ -8,13 +8,31 @@ use std::path::Path;
 
 use crate::convert::Convert;
 use crate::eval::Context;
-use crate::extn::core::exception::{ArgumentError, Fatal, LoadError, RubyException, TypeError};
+use crate::extn::core::exception::{
+    ArgumentError, Fatal, LoadError, RubyException, SystemStackError, TypeError,
+};
 use crate::fs::{self, RUBY_LOAD_PATH};
 use crate::value::Value;
 use crate::Artichoke;
 
 const RUBY_EXTENSION: &str = "rb";
 
+/// Push an eval [`Context`] with
+/// [`State::push_context_checked`](crate::state::State::push_context_checked),
+/// surfacing a depth limit failure as a `SystemStackError`.
+fn push_context_checked(
+    interp: &Artichoke,
+    context: Context,
+) -> Result<(), Box<dyn RubyException>> {
+    interp
+        .0
+        .borrow_mut()
+        .push_context_checked(context)
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(SystemStackError::new(interp, "stack level too deep"))
+        })
+}
+
 pub fn load(interp: &Artichoke, filename: Value) -> Result<Value, Box<dyn RubyException>> {
     let ruby_type = filename.pretty_name();
     let filename = if let Ok(filename) = filename.clone().try_into::<&[u8]>() {
@@ -63,7 +81,7 @@ pub fn load(interp: &Artichoke, filename: Value) -> Result<Value, Box<dyn RubyEx
     // arbitrary other files, including some child sources that may
     // depend on these module definitions.
     let context = Context::new(filename.to_vec());
-    interp.push_context(context);
+    push_context_checked(interp, context)?;
     // Require Rust File first because an File may define classes and
     // module with `LoadSources` and Ruby files can require arbitrary
     // other files, including some child sources that may depend on these
@@ -87,9 +105,12 @@ pub fn load(interp: &Artichoke, filename: Value) -> Result<Value, Box<dyn RubyEx
         api.vfs.read_file(path.as_path())
     };
     if let Ok(contents) = contents {
-        // We need to be sure we don't leak anything by unwinding past
-        // this point. This likely requires a significant refactor to
-        // require_impl.
+        // Exceptions raised by the required source must propagate to the
+        // requiring Ruby frame as Ruby exceptions, which needs the
+        // `longjmp`-based unwind of `unchecked_eval`. We need to be sure we
+        // don't leak anything by unwinding past this point. This likely
+        // requires a significant refactor to require_impl.
+        #[allow(deprecated)]
         interp.unchecked_eval(contents.as_slice());
     }
     interp.pop_context();
@@ -141,12 +162,15 @@ pub fn require(
             api.vfs.is_file(path.as_path())
         };
         if is_file {
-            let metadata = {
+            let (metadata, already_loaded) = {
                 let api = interp.0.borrow();
-                api.vfs.metadata(path.as_path()).unwrap_or_default()
+                (
+                    api.vfs.metadata(path.as_path()).unwrap_or_default(),
+                    api.is_loaded(&path.to_string_lossy()),
+                )
             };
             // If a file is already required, short circuit.
-            if metadata.is_already_required() {
+            if metadata.is_already_required() || already_loaded {
                 return Ok(interp.convert(false));
             }
             // Require Rust File first because an File may define classes
@@ -154,7 +178,7 @@ pub fn require(
             // arbitrary other files, including some child sources that may
             // depend on these module definitions.
             let context = Context::new(fs::osstr_to_bytes(interp, path.as_os_str())?.to_vec());
-            interp.push_context(context.clone());
+            push_context_checked(interp, context)?;
             // Require Rust File first because an File may define classes and
             // module with `LoadSources` and Ruby files can require arbitrary
             // other files, including some child sources that may depend on these
@@ -178,9 +202,12 @@ pub fn require(
                 api.vfs.read_file(path.as_path())
             };
             if let Ok(contents) = contents {
-                // We need to be sure we don't leak anything by unwinding past
-                // this point. This likely requires a significant refactor to
-                // require_impl.
+                // Exceptions raised by the required source must propagate to
+                // the requiring Ruby frame as Ruby exceptions, which needs the
+                // `longjmp`-based unwind of `unchecked_eval`. We need to be
+                // sure we don't leak anything by unwinding past this point.
+                // This likely requires a significant refactor to require_impl.
+                #[allow(deprecated)]
                 interp.unchecked_eval(contents.as_slice());
             }
             interp.pop_context();
@@ -195,6 +222,8 @@ pub fn require(
                         "Unable to set require metadata in the Artichoke virtual filesystem",
                     )
                 })?;
+            drop(borrow);
+            interp.0.borrow_mut().mark_loaded(&path.to_string_lossy());
             trace!(
                 r#"Successful require of {:?} at {:?}"#,
                 <&BStr>::from(filename),
@@ -208,12 +237,15 @@ pub fn require(
                 api.vfs.is_file(path.as_path())
             };
             if is_file {
-                let metadata = {
+                let (metadata, already_loaded) = {
                     let api = interp.0.borrow();
-                    api.vfs.metadata(path.as_path()).unwrap_or_default()
+                    (
+                        api.vfs.metadata(path.as_path()).unwrap_or_default(),
+                        api.is_loaded(&path.to_string_lossy()),
+                    )
                 };
                 // If a file is already required, short circuit.
-                if metadata.is_already_required() {
+                if metadata.is_already_required() || already_loaded {
                     return Ok(interp.convert(false));
                 }
                 // Require Rust File first because an File may define classes
@@ -221,7 +253,7 @@ pub fn require(
                 // arbitrary other files, including some child sources that may
                 // depend on these module definitions.
                 let context = Context::new(fs::osstr_to_bytes(interp, path.as_os_str())?.to_vec());
-                interp.push_context(context);
+                push_context_checked(interp, context)?;
                 // Require Rust File first because an File may define classes and
                 // module with `LoadSources` and Ruby files can require arbitrary
                 // other files, including some child sources that may depend on these
@@ -245,9 +277,13 @@ pub fn require(
                     api.vfs.read_file(path.as_path())
                 };
                 if let Ok(contents) = contents {
-                    // We need to be sure we don't leak anything by unwinding past
-                    // this point. This likely requires a significant refactor to
-                    // require_impl.
+                    // Exceptions raised by the required source must propagate
+                    // to the requiring Ruby frame as Ruby exceptions, which
+                    // needs the `longjmp`-based unwind of `unchecked_eval`. We
+                    // need to be sure we don't leak anything by unwinding past
+                    // this point. This likely requires a significant refactor
+                    // to require_impl.
+                    #[allow(deprecated)]
                     interp.unchecked_eval(contents.as_slice());
                 }
                 interp.pop_context();
@@ -262,6 +298,8 @@ pub fn require(
                             "Unable to set require metadata in the Artichoke virtual filesystem",
                         )
                     })?;
+                drop(borrow);
+                interp.0.borrow_mut().mark_loaded(&path.to_string_lossy());
                 trace!(
                     r#"Successful require of {:?} at {:?}"#,
                     <&BStr>::from(filename),
@@ -292,12 +330,15 @@ pub fn require(
             ),
         )));
     }
-    let metadata = {
+    let (metadata, already_loaded) = {
         let api = interp.0.borrow();
-        api.vfs.metadata(path.as_path()).unwrap_or_default()
+        (
+            api.vfs.metadata(path.as_path()).unwrap_or_default(),
+            api.is_loaded(&path.to_string_lossy()),
+        )
     };
     // If a file is already required, short circuit.
-    if metadata.is_already_required() {
+    if metadata.is_already_required() || already_loaded {
         return Ok(interp.convert(false));
     }
     // Require Rust File first because an File may define classes
@@ -305,7 +346,7 @@ pub fn require(
     // arbitrary other files, including some child sources that may
     // depend on these module definitions.
     let context = Context::new(fs::osstr_to_bytes(interp, path.as_os_str())?.to_vec());
-    interp.push_context(context);
+    push_context_checked(interp, context)?;
     // Require Rust File first because an File may define classes and
     // module with `LoadSources` and Ruby files can require arbitrary
     // other files, including some child sources that may depend on these
@@ -329,9 +370,12 @@ pub fn require(
         api.vfs.read_file(path.as_path())
     };
     if let Ok(contents) = contents {
-        // We need to be sure we don't leak anything by unwinding past
-        // this point. This likely requires a significant refactor to
-        // require_impl.
+        // Exceptions raised by the required source must propagate to the
+        // requiring Ruby frame as Ruby exceptions, which needs the
+        // `longjmp`-based unwind of `unchecked_eval`. We need to be sure we
+        // don't leak anything by unwinding past this point. This likely
+        // requires a significant refactor to require_impl.
+        #[allow(deprecated)]
         interp.unchecked_eval(contents.as_slice());
     }
     interp.pop_context();
@@ -346,6 +390,8 @@ pub fn require(
                 "Unable to set require metadata in the Artichoke virtual filesystem",
             )
         })?;
+    drop(borrow);
+    interp.0.borrow_mut().mark_loaded(&path.to_string_lossy());
     trace!(
         r#"Successful require of "{:?}" at {:?}"#,
         <&BStr>::from(filename),
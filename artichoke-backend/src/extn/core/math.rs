@@ -0,0 +1,680 @@
+//! [ruby/spec](https://github.com/ruby/spec/tree/master/core/math)
+//!
+//! The `Math` module contains module functions for basic trigonometric and
+//! transcendental functions. Functions are implemented natively on top of the
+//! Rust `std` float intrinsics where they exist and libm for the C99 special
+//! functions (`erf`, `erfc`, `tgamma`, `lgamma`) that `std` does not expose.
+
+use artichoke_core::value::Value as _;
+#[cfg(feature = "artichoke-debug")]
+use backtrace::Backtrace;
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+use std::os::raw::c_int;
+
+use crate::class;
+use crate::convert::Convert;
+use crate::def::EnclosingRubyScope;
+use crate::extn::core::exception::{self, Fatal, RubyException, TypeError};
+use crate::module;
+use crate::sys;
+use crate::types::{Float, Int, Ruby};
+use crate::value::Value;
+use crate::{Artichoke, ArtichokeError};
+
+// `std` does not expose the C99 special functions. The backend already links
+// against libc, which bundles libm on all supported targets.
+extern "C" {
+    fn erf(x: f64) -> f64;
+    fn erfc(x: f64) -> f64;
+    fn tgamma(x: f64) -> f64;
+    fn lgamma_r(x: f64, signp: *mut c_int) -> f64;
+}
+
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    if interp.0.borrow().module_spec::<Math>().is_some() {
+        return Ok(());
+    }
+    let spec = module::Spec::new("Math", None);
+    module::Builder::for_spec(interp, &spec)
+        .add_module_method("acos", Math::acos, sys::mrb_args_req(1))
+        .add_module_method("asin", Math::asin, sys::mrb_args_req(1))
+        .add_module_method("atan", Math::atan, sys::mrb_args_req(1))
+        .add_module_method("atan2", Math::atan2, sys::mrb_args_req(2))
+        .add_module_method("cbrt", Math::cbrt, sys::mrb_args_req(1))
+        .add_module_method("cos", Math::cos, sys::mrb_args_req(1))
+        .add_module_method("cosh", Math::cosh, sys::mrb_args_req(1))
+        .add_module_method("erf", Math::erf, sys::mrb_args_req(1))
+        .add_module_method("erfc", Math::erfc, sys::mrb_args_req(1))
+        .add_module_method("exp", Math::exp, sys::mrb_args_req(1))
+        .add_module_method("gamma", Math::gamma, sys::mrb_args_req(1))
+        .add_module_method("hypot", Math::hypot, sys::mrb_args_req(2))
+        .add_module_method("lgamma", Math::lgamma, sys::mrb_args_req(1))
+        .add_module_method("log", Math::log, sys::mrb_args_req_and_opt(1, 1))
+        .add_module_method("log10", Math::log10, sys::mrb_args_req(1))
+        .add_module_method("log2", Math::log2, sys::mrb_args_req(1))
+        .add_module_method("sin", Math::sin, sys::mrb_args_req(1))
+        .add_module_method("sinh", Math::sinh, sys::mrb_args_req(1))
+        .add_module_method("sqrt", Math::sqrt, sys::mrb_args_req(1))
+        .add_module_method("tan", Math::tan, sys::mrb_args_req(1))
+        .add_module_method("tanh", Math::tanh, sys::mrb_args_req(1))
+        .define()?;
+
+    let argument_error = interp
+        .0
+        .borrow()
+        .class_spec::<exception::ArgumentError>()
+        .cloned()
+        .ok_or(ArtichokeError::New)?;
+    let domainerror_spec = class::Spec::new(
+        "DomainError",
+        Some(EnclosingRubyScope::module(&spec)),
+        None,
+    );
+    class::Builder::for_spec(interp, &domainerror_spec)
+        .with_super_class(Some(&argument_error))
+        .define()?;
+    interp.0.borrow_mut().def_class::<DomainError>(domainerror_spec);
+    interp.0.borrow_mut().def_module::<Math>(spec);
+
+    let borrow = interp.0.borrow();
+    let mrb = borrow.mrb;
+    let rclass = borrow
+        .module_spec::<Math>()
+        .and_then(|spec| spec.rclass(interp))
+        .ok_or(ArtichokeError::New)?;
+    unsafe {
+        sys::mrb_define_const(
+            mrb,
+            rclass,
+            b"PI\0".as_ptr() as *const i8,
+            sys::mrb_sys_float_value(mrb, std::f64::consts::PI),
+        );
+        sys::mrb_define_const(
+            mrb,
+            rclass,
+            b"E\0".as_ptr() as *const i8,
+            sys::mrb_sys_float_value(mrb, std::f64::consts::E),
+        );
+    }
+    drop(borrow);
+    trace!("Patched Math onto interpreter");
+    Ok(())
+}
+
+pub struct Math;
+
+impl Math {
+    /// Extract a `Float` argument per MRI `Float()` semantics for `Math`
+    /// module functions.
+    ///
+    /// ```txt
+    /// [2.6.3] > Math.sqrt('a')
+    /// TypeError (can't convert String into Float)
+    /// ```
+    fn float_arg(interp: &Artichoke, value: &Value) -> Result<Float, Box<dyn RubyException>> {
+        match value.ruby_type() {
+            Ruby::Float => value
+                .clone()
+                .try_into::<Float>()
+                .map_err(|_| -> Box<dyn RubyException> {
+                    Box::new(Fatal::new(
+                        interp,
+                        "Failed to convert Ruby Float into Rust Float",
+                    ))
+                }),
+            #[allow(clippy::cast_precision_loss)]
+            Ruby::Fixnum => value
+                .clone()
+                .try_into::<Int>()
+                .map(|value| value as Float)
+                .map_err(|_| -> Box<dyn RubyException> {
+                    Box::new(Fatal::new(
+                        interp,
+                        "Failed to convert Ruby Integer into Rust Int",
+                    ))
+                }),
+            _ => Err(Box::new(TypeError::new(
+                interp,
+                format!("can't convert {} into Float", value.pretty_name()),
+            ))),
+        }
+    }
+
+    // ```txt
+    // [2.6.3] > Math.sqrt(-1)
+    // Math::DomainError (Numerical argument is out of domain - "sqrt")
+    // ```
+    fn domain_error(interp: &Artichoke, name: &str) -> Box<dyn RubyException> {
+        Box::new(DomainError::new(
+            interp,
+            format!("Numerical argument is out of domain - \"{}\"", name),
+        ))
+    }
+
+    /// The gamma function has poles at zero and the negative integers, where
+    /// MRI raises `Math::DomainError`.
+    #[allow(clippy::float_cmp)]
+    fn is_negative_integer(value: Float) -> bool {
+        value < 0.0 && value.floor() == value
+    }
+
+    pub unsafe extern "C" fn acos(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            if (-1.0..=1.0).contains(&value) {
+                Ok(interp.convert(value.acos()))
+            } else {
+                Err(Self::domain_error(&interp, "acos"))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn asin(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            if (-1.0..=1.0).contains(&value) {
+                Ok(interp.convert(value.asin()))
+            } else {
+                Err(Self::domain_error(&interp, "asin"))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn atan(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result =
+            Self::float_arg(&interp, &value).map(|value| interp.convert(value.atan()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn atan2(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let (value, other) = mrb_get_args!(mrb, required = 2);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let other = Value::new(&interp, other);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            let other = Self::float_arg(&interp, &other)?;
+            Ok(interp.convert(value.atan2(other)))
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn cbrt(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result =
+            Self::float_arg(&interp, &value).map(|value| interp.convert(value.cbrt()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn cos(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).map(|value| interp.convert(value.cos()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn cosh(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result =
+            Self::float_arg(&interp, &value).map(|value| interp.convert(value.cosh()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn erf(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value)
+            .map(|value| interp.convert(unsafe { erf(value) }));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn erfc(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value)
+            .map(|value| interp.convert(unsafe { erfc(value) }));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn exp(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).map(|value| interp.convert(value.exp()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn gamma(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            if value.is_infinite() && value.is_sign_negative()
+                || Self::is_negative_integer(value)
+            {
+                Err(Self::domain_error(&interp, "gamma"))
+            } else {
+                Ok(interp.convert(unsafe { tgamma(value) }))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn hypot(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let (value, other) = mrb_get_args!(mrb, required = 2);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let other = Value::new(&interp, other);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            let other = Self::float_arg(&interp, &other)?;
+            Ok(interp.convert(value.hypot(other)))
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn lgamma(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            if value.is_infinite() && value.is_sign_negative() {
+                Err(Self::domain_error(&interp, "lgamma"))
+            } else {
+                let mut sign = 0;
+                let result = unsafe { lgamma_r(value, &mut sign) };
+                Ok(interp
+                    .convert(vec![interp.convert(result), interp.convert(Int::from(sign))]))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn log(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let (value, base) = mrb_get_args!(mrb, required = 1, optional = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            if value < 0.0 {
+                return Err(Self::domain_error(&interp, "log"));
+            }
+            if let Some(base) = base {
+                let base = Self::float_arg(&interp, &Value::new(&interp, base))?;
+                if base < 0.0 {
+                    return Err(Self::domain_error(&interp, "log"));
+                }
+                Ok(interp.convert(value.ln() / base.ln()))
+            } else {
+                Ok(interp.convert(value.ln()))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn log10(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            if value < 0.0 {
+                Err(Self::domain_error(&interp, "log10"))
+            } else {
+                Ok(interp.convert(value.log10()))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn log2(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            if value < 0.0 {
+                Err(Self::domain_error(&interp, "log2"))
+            } else {
+                Ok(interp.convert(value.log2()))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn sin(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).map(|value| interp.convert(value.sin()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn sinh(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result =
+            Self::float_arg(&interp, &value).map(|value| interp.convert(value.sinh()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn sqrt(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).and_then(|value| {
+            if value < 0.0 {
+                Err(Self::domain_error(&interp, "sqrt"))
+            } else {
+                Ok(interp.convert(value.sqrt()))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn tan(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::float_arg(&interp, &value).map(|value| interp.convert(value.tan()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn tanh(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result =
+            Self::float_arg(&interp, &value).map(|value| interp.convert(value.tanh()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+}
+
+// `DomainError` mirrors the expansion of `ruby_exception_impl!` in
+// `extn::core::exception`. It cannot use the macro because the class is
+// registered by `math::init` rather than `exception::init`.
+pub struct DomainError {
+    interp: Artichoke,
+    message: Cow<'static, [u8]>,
+    #[cfg(feature = "artichoke-debug")]
+    backtrace: Backtrace,
+}
+
+impl DomainError {
+    pub fn new<S>(interp: &Artichoke, message: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        let message = match message.into() {
+            Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+            Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+        };
+        Self {
+            interp: interp.clone(),
+            message,
+            #[cfg(feature = "artichoke-debug")]
+            backtrace: Backtrace::new(),
+        }
+    }
+}
+
+impl From<DomainError> for Box<dyn RubyException> {
+    fn from(exception: DomainError) -> Box<dyn RubyException> {
+        Box::new(exception)
+    }
+}
+
+impl RubyException for DomainError {
+    fn message(&self) -> &[u8] {
+        self.message.as_ref()
+    }
+
+    fn name(&self) -> String {
+        self.interp
+            .0
+            .borrow()
+            .class_spec::<Self>()
+            .map(|spec| spec.name().to_owned())
+            .unwrap_or_default()
+    }
+
+    fn rclass(&self) -> Option<*mut sys::RClass> {
+        self.interp
+            .0
+            .borrow()
+            .class_spec::<Self>()
+            .and_then(|spec| spec.rclass(&self.interp))
+    }
+}
+
+impl fmt::Debug for DomainError {
+    #[cfg(feature = "artichoke-debug")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{} ({})", classname, message)?;
+        write!(f, "\n{:?}", self.backtrace)
+    }
+
+    #[cfg(not(feature = "artichoke-debug"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{} ({})", classname, message)
+    }
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{}: {}", classname, message)
+    }
+}
+
+impl PartialEq for DomainError {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.message() == other.message()
+    }
+}
+
+impl PartialEq<Box<dyn RubyException>> for DomainError {
+    fn eq(&self, other: &Box<dyn RubyException>) -> bool {
+        self.name() == other.name() && self.message() == other.message()
+    }
+}
+
+impl error::Error for DomainError {
+    fn description(&self) -> &str {
+        "Ruby Exception: Math::DomainError"
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+
+    #[test]
+    fn math_functions() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"Math.sqrt(9)").expect("eval");
+        assert_eq!(result.try_into::<f64>().expect("convert"), 3.0);
+        let result = interp.eval(b"Math.hypot(3, 4)").expect("eval");
+        assert_eq!(result.try_into::<f64>().expect("convert"), 5.0);
+        let result = interp.eval(b"Math.cos(0)").expect("eval");
+        assert_eq!(result.try_into::<f64>().expect("convert"), 1.0);
+        let result = interp.eval(b"Math.atan2(0, 1)").expect("eval");
+        assert_eq!(result.try_into::<f64>().expect("convert"), 0.0);
+    }
+
+    #[test]
+    fn log_with_base() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"Math.log(8, 2)").expect("eval");
+        assert_eq!(result.try_into::<f64>().expect("convert"), 3.0);
+        let result = interp.eval(b"Math.log(Math::E)").expect("eval");
+        assert_eq!(result.try_into::<f64>().expect("convert"), 1.0);
+    }
+
+    #[test]
+    fn constants() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"Math::PI").expect("eval");
+        assert_eq!(
+            result.try_into::<f64>().expect("convert"),
+            std::f64::consts::PI
+        );
+        let result = interp.eval(b"Math::E").expect("eval");
+        assert_eq!(
+            result.try_into::<f64>().expect("convert"),
+            std::f64::consts::E
+        );
+    }
+
+    #[test]
+    fn gamma() {
+        let interp = crate::interpreter().expect("eval");
+        let result = interp.eval(b"Math.gamma(5)").expect("eval");
+        assert_eq!(result.try_into::<f64>().expect("convert"), 24.0);
+        let result = interp
+            .eval(b"Math.lgamma(6) == [Math.log(120), 1]")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn domain_error_is_an_argument_error() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(
+                br#"
+                begin
+                  Math.sqrt(-1)
+                rescue ArgumentError => err
+                  err.message
+                end
+                "#,
+            )
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            r#"Numerical argument is out of domain - "sqrt""#
+        );
+        let result = interp
+            .eval(b"begin; Math.log(-1); rescue Math::DomainError; :rescued; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            "rescued"
+        );
+    }
+
+    #[test]
+    fn non_numeric_is_a_type_error() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"Math.sin('a')").map(|_| ());
+        assert_eq!(
+            result,
+            Err(crate::ArtichokeError::Exec(
+                "TypeError: can't convert String into Float".to_owned()
+            ))
+        );
+    }
+}
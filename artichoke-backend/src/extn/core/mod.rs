@@ -11,11 +11,14 @@ pub mod enumerable;
 pub mod enumerator;
 pub mod env;
 pub mod exception;
+pub mod fiber;
 pub mod float;
 pub mod hash;
 pub mod integer;
+pub mod io;
 pub mod kernel;
 pub mod matchdata;
+pub mod math;
 pub mod method;
 pub mod module;
 pub mod numeric;
@@ -44,12 +47,17 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     comparable::init(interp)?;
     enumerator::init(interp)?;
     env::mruby::init(interp)?;
+    // `FiberError` depends on: `Exception`
+    fiber::init(interp)?;
     hash::init(interp)?;
     numeric::init(interp)?;
     integer::init(interp)?;
+    io::init(interp)?;
     float::init(interp)?;
     kernel::init(interp)?;
     matchdata::init(interp)?;
+    // `Math::DomainError` depends on: `ArgumentError`
+    math::init(interp)?;
     method::init(interp)?;
     module::init(interp)?;
     object::init(interp)?;
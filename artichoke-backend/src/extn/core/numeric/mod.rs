@@ -1,13 +1,29 @@
 use artichoke_core::eval::Eval;
+use std::borrow::Cow;
 
 use crate::class;
+use crate::convert::Convert;
+use crate::extn::core::comparable::Comparable;
+use crate::extn::core::exception::{self, Fatal, RubyException, TypeError};
+use crate::sys;
+use crate::types::Float;
+use crate::value::{Value, ValueLike};
 use crate::{Artichoke, ArtichokeError};
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     if interp.0.borrow().class_spec::<Numeric>().is_some() {
         return Ok(());
     }
+    let borrow = interp.0.borrow();
+    let comparable = borrow
+        .module_spec::<Comparable>()
+        .ok_or(ArtichokeError::NotDefined(Cow::Borrowed("Comparable")))?;
     let spec = class::Spec::new("Numeric", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .include_module(comparable)
+        .add_method("coerce", Numeric::coerce, sys::mrb_args_req(1))
+        .define()?;
+    drop(borrow);
     interp.0.borrow_mut().def_class::<Numeric>(spec);
     interp.eval(&include_bytes!("numeric.rb")[..])?;
     trace!("Patched Numeric onto interpreter");
@@ -15,3 +31,153 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 }
 
 pub struct Numeric;
+
+impl Numeric {
+    /// `Numeric#coerce`.
+    ///
+    /// If `other` is an instance of the same class as the receiver, the pair
+    /// is returned as is. Otherwise both values are represented as `Float`s.
+    /// The returned two-element array is ordered `[coerced_other,
+    /// coerced_self]`, which is the contract the coerce-retrying arithmetic
+    /// operators in `integer.rb` and `float.rb` rely on.
+    pub unsafe extern "C" fn coerce(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let other = Value::new(&interp, other);
+        let result = Self::coerced_pair(&interp, value, other);
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    fn coerced_pair(
+        interp: &Artichoke,
+        value: Value,
+        other: Value,
+    ) -> Result<Value, Box<dyn RubyException>> {
+        let class = value.funcall::<Value>("class", &[], None).map_err(|_| {
+            Box::new(Fatal::new(
+                interp,
+                "Failed to extract class from Numeric receiver",
+            )) as Box<dyn RubyException>
+        })?;
+        let same_class = other
+            .funcall::<bool>("instance_of?", &[class], None)
+            .map_err(|_| {
+                Box::new(Fatal::new(
+                    interp,
+                    "Failed to compare Numeric receiver and argument classes",
+                )) as Box<dyn RubyException>
+            })?;
+        if same_class {
+            let pair = vec![other, value];
+            Ok(interp.convert(pair))
+        } else {
+            let coerced_other = Self::to_float(interp, &other, &value)?;
+            let coerced_self = Self::to_float(interp, &value, &value)?;
+            let pair = vec![interp.convert(coerced_other), interp.convert(coerced_self)];
+            Ok(interp.convert(pair))
+        }
+    }
+
+    fn to_float(
+        interp: &Artichoke,
+        value: &Value,
+        receiver: &Value,
+    ) -> Result<Float, Box<dyn RubyException>> {
+        value.funcall::<Float>("to_f", &[], None).map_err(|_| {
+            Box::new(TypeError::new(
+                interp,
+                format!(
+                    "{} can't be coerced into {}",
+                    value.pretty_name(),
+                    receiver.pretty_name()
+                ),
+            )) as Box<dyn RubyException>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::types::{Float, Int};
+    use crate::value::ValueLike;
+    use crate::ArtichokeError;
+
+    #[test]
+    fn coerce_same_class_returns_pair_as_is() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"1.coerce(2) == [2, 1]").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn coerce_mixed_types_to_float() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"1.coerce(2.5)").expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<Float>>().expect("convert"),
+            vec![2.5, 1.0]
+        );
+    }
+
+    #[test]
+    fn coerce_non_numeric_raises_type_error() {
+        let interp = crate::interpreter().expect("init");
+        let err = interp.eval(b"1.coerce(nil)").unwrap_err();
+        match err {
+            ArtichokeError::Exec(message) => {
+                assert!(message.starts_with("TypeError"), "got: {}", message)
+            }
+            err => panic!("expected TypeError, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn numeric_subclass_arithmetic_through_coerce() {
+        let interp = crate::interpreter().expect("init");
+        interp
+            .eval(
+                br#"
+                class MyNum < Numeric
+                  attr_reader :value
+
+                  def initialize(value)
+                    @value = value
+                  end
+
+                  def coerce(other)
+                    if other.instance_of?(MyNum)
+                      [other, self]
+                    else
+                      [MyNum.new(other.to_i), self]
+                    end
+                  end
+
+                  def +(other)
+                    lhs, rhs = coerce(other)
+                    MyNum.new(lhs.value + rhs.value)
+                  end
+
+                  def to_i
+                    @value
+                  end
+                end
+                "#,
+            )
+            .expect("define MyNum");
+        let result = interp
+            .eval(b"(MyNum.new(3) + 2).class == MyNum")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"(2 + MyNum.new(3)).value").expect("eval");
+        assert_eq!(result.try_into::<Int>().expect("convert"), 5);
+    }
+}
@@ -76,5 +76,15 @@ class Numeric
     self == 0 # rubocop:disable Style/NumericPredicate
   end
 
+  # Retry a failed arithmetic operation through the other operand's #coerce
+  # protocol. Called by the operator wrappers in Integer and Float when the
+  # native operator raises a TypeError for a mismatched operand.
+  def __coerce_retry(operator, other)
+    raise TypeError, "#{other.class} can't be coerced into #{self.class}" unless other.respond_to?(:coerce)
+
+    lhs, rhs = other.coerce(self)
+    lhs.send(operator, rhs)
+  end
+
   alias succ next
 end
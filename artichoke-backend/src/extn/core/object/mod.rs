@@ -1,6 +1,11 @@
 use artichoke_core::eval::Eval;
 
 use crate::class;
+use crate::convert::Convert;
+use crate::extn::core::exception::{self, RubyException, TypeError};
+use crate::sys;
+use crate::types::Ruby;
+use crate::value::Value;
 use crate::{Artichoke, ArtichokeError};
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
@@ -8,6 +13,15 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         return Ok(());
     }
     let spec = class::Spec::new("Object", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method("class", Object::class, sys::mrb_args_none())
+        .add_method("is_a?", Object::is_a, sys::mrb_args_req(1))
+        .add_method("kind_of?", Object::is_a, sys::mrb_args_req(1))
+        .add_method("nil?", Object::nil, sys::mrb_args_none())
+        .add_method("freeze", Object::freeze, sys::mrb_args_none())
+        .add_method("frozen?", Object::frozen, sys::mrb_args_none())
+        .add_method("dup", Object::dup, sys::mrb_args_none())
+        .define()?;
     interp.0.borrow_mut().def_class::<Object>(spec);
     interp.eval(&include_bytes!("object.rb")[..])?;
     trace!("Patched Object onto interpreter");
@@ -15,3 +29,105 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 }
 
 pub struct Object;
+
+impl Object {
+    pub unsafe extern "C" fn class(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        // `mrb_obj_class` skips over singleton classes, so objects with
+        // singleton methods still report their real class.
+        let class = sys::mrb_obj_class(mrb, slf);
+        sys::mrb_sys_class_value(class)
+    }
+
+    pub unsafe extern "C" fn is_a(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let class = Value::new(&interp, other);
+        let result = match class.ruby_type() {
+            Ruby::Class | Ruby::Module | Ruby::SingletonClass => {
+                // `mrb_obj_is_kind_of` walks the receiver's ancestor chain,
+                // which includes mixed-in modules.
+                let is_kind_of =
+                    sys::mrb_obj_is_kind_of(mrb, slf, sys::mrb_sys_class_ptr(other)) != 0;
+                Ok(interp.convert(is_kind_of))
+            }
+            _ => Err(Box::new(TypeError::new(&interp, "class or module required"))
+                as Box<dyn RubyException>),
+        };
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn nil(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let interp = unwrap_interpreter!(mrb);
+        let value = interp.convert(sys::mrb_sys_value_is_nil(slf));
+        value.inner()
+    }
+
+    pub unsafe extern "C" fn freeze(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        // `mrb_obj_freeze` returns the receiver, matching `Object#freeze`.
+        sys::mrb_obj_freeze(mrb, slf)
+    }
+
+    pub unsafe extern "C" fn frozen(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let interp = unwrap_interpreter!(mrb);
+        let value = interp.convert(sys::mrb_sys_obj_frozen(mrb, slf));
+        value.inner()
+    }
+
+    pub unsafe extern "C" fn dup(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        sys::mrb_obj_dup(mrb, slf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::value::ValueLike;
+
+    #[test]
+    fn class_and_type_predicates() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"1.class.name").expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "Integer");
+        let result = interp.eval(b"1.is_a?(Numeric)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"1.kind_of?(String)").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"nil.nil?").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"0.nil?").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"begin; 1.is_a?('not a class'); rescue TypeError; :raised; end")
+            .expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "raised");
+    }
+
+    #[test]
+    fn freeze_and_dup() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"'x'.freeze.frozen?").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"'x'.frozen?").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        // `dup` returns a shallow copy that does not carry frozen state.
+        let result = interp.eval(b"s = 'x'.freeze; s.dup.frozen?").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"a = [1, 2]; b = a.dup; b << 3; [a.length, b.length]")
+            .expect("eval");
+        assert_eq!(result.try_into::<Vec<i64>>().expect("convert"), vec![2, 3]);
+    }
+}
@@ -1,6 +1,9 @@
 use artichoke_core::eval::Eval;
+use std::convert::TryFrom;
 
 use crate::class;
+use crate::sys;
+use crate::types::Int;
 use crate::{Artichoke, ArtichokeError};
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
@@ -8,6 +11,11 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         return Ok(());
     }
     let spec = class::Spec::new("Proc", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method("call", Proc::call, sys::mrb_args_rest())
+        .add_method("[]", Proc::call, sys::mrb_args_rest())
+        .add_method("===", Proc::call, sys::mrb_args_rest())
+        .define()?;
     interp.0.borrow_mut().def_class::<Proc>(spec);
     interp.eval(&include_bytes!("proc.rb")[..])?;
     trace!("Patched Proc onto interpreter");
@@ -15,3 +23,77 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 }
 
 pub struct Proc;
+
+impl Proc {
+    unsafe extern "C" fn call(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let args = mrb_get_args!(mrb, *args);
+        // The VM re-enters through `mrb_yield_argv`, so Ruby exceptions
+        // raised by the proc body propagate to the caller unchanged.
+        let argc = Int::try_from(args.len()).unwrap_or_default();
+        sys::mrb_yield_argv(mrb, slf, argc, args.as_ptr())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+
+    use crate::convert::Convert;
+
+    #[test]
+    fn call_element_reference_and_case_equality() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"double = ->(x) { x * 2 }; double.call(5)")
+            .expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 10);
+        let result = interp.eval(b"double[7]").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 14);
+        let result = interp.eval(b"double.(3)").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 6);
+        let result = interp
+            .eval(b"even = ->(x) { x % 2 == 0 }; case 4; when even then :even; else :odd; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("even")
+        );
+    }
+
+    #[test]
+    fn curry_accumulates_arguments() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"double = ->(x) { x * 2 }; double.curry.(5)")
+            .expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 10);
+        let result = interp
+            .eval(b"add = ->(x, y) { x + y }; add.curry[1][2]")
+            .expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 3);
+    }
+
+    #[test]
+    fn call_proc_from_rust() {
+        let interp = crate::interpreter().expect("init");
+        let proc = interp.eval(b"->(x) { x * 2 }").expect("eval");
+        let result = interp
+            .call_proc(&proc, &[interp.convert(5)])
+            .expect("call_proc");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 10);
+        // Ruby exceptions surface as `ArtichokeError::Exec`.
+        let proc = interp
+            .eval(b"-> { raise ArgumentError, 'nope' }")
+            .expect("eval");
+        let err = interp.call_proc(&proc, &[]).map(|_| ()).unwrap_err();
+        assert!(
+            format!("{}", err).contains("nope"),
+            "expected proc exception, got {:?}",
+            err
+        );
+        // Non-`Proc` receivers are a typed error.
+        let value = interp.eval(b"42").expect("eval");
+        assert!(interp.call_proc(&value, &[]).is_err());
+    }
+}
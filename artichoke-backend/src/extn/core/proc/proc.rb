@@ -1,10 +1,6 @@
 # frozen_string_literal: true
 
 class Proc
-  def ===(*args)
-    call(*args)
-  end
-
   def <<(other)
     ->(*args, &block) { call(other.call(*args, &block)) }
   end
@@ -28,6 +28,13 @@ where
     }
 }
 
+impl<T> Rand<T> {
+    /// The seed this backend was created with.
+    pub fn seed_value(&self) -> u64 {
+        self.seed
+    }
+}
+
 impl<T> backend::Rand for Rand<T>
 where
     T: 'static + Rng,
@@ -3,6 +3,7 @@ use rand::{self, Rng, RngCore};
 use std::convert::TryFrom;
 use std::ptr;
 
+use crate::class;
 use crate::convert::{Convert, RustBackedValue};
 use crate::extn::core::exception::{ArgumentError, Fatal, RubyException};
 use crate::sys;
@@ -24,6 +25,25 @@ pub fn default() -> Random {
 pub struct Random(Box<dyn backend::Rand>);
 
 impl Random {
+    /// Create a `Random` seeded with a specific value.
+    ///
+    /// `Random`s created with the same seed return the same sequence of
+    /// random data.
+    pub fn seeded(seed: u64) -> Self {
+        Self(backend::rand::new(Some(seed)))
+    }
+
+    /// The seed this `Random` was created with.
+    ///
+    /// Returns [`None`] for backends that do not track their seed, for
+    /// example the [`backend::default`] backend which delegates to the
+    /// interpreter-global PRNG.
+    pub fn seed_value(&self) -> Option<u64> {
+        self.inner()
+            .downcast_ref::<backend::rand::Rand<rand::rngs::SmallRng>>()
+            .map(backend::rand::Rand::seed_value)
+    }
+
     fn inner(&self) -> &dyn backend::Rand {
         self.0.as_ref()
     }
@@ -47,7 +67,7 @@ pub fn initialize(
     let rand = if let Some(seed) = seed {
         let seed = seed.implicitly_convert_to_int()?;
         #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
-        Random(backend::rand::new(Some(seed as u64)))
+        Random::seeded(seed as u64)
     } else {
         Random(backend::rand::new(None))
     };
@@ -57,23 +77,17 @@ pub fn initialize(
 }
 
 pub fn eql(interp: &Artichoke, rand: Value, other: Value) -> Result<Value, Box<dyn RubyException>> {
-    if let Ok(rand) = unsafe { Random::try_from_ruby(interp, &rand) } {
-        if let Ok(other) = unsafe { Random::try_from_ruby(interp, &other) } {
-            if ptr::eq(rand.as_ref(), other.as_ref()) {
-                Ok(interp.convert(true))
-            } else {
-                let this_seed = rand.borrow().inner().seed(interp)?;
-                let other_seed = other.borrow().inner().seed(interp)?;
-                Ok(interp.convert(this_seed == other_seed))
-            }
+    let rand = class::try_get_data::<Random>(interp, &rand)?;
+    if let Some(other) = class::get_data::<Random>(interp, &other) {
+        if ptr::eq(rand.as_ref(), other.as_ref()) {
+            Ok(interp.convert(true))
         } else {
-            Ok(interp.convert(false))
+            let this_seed = rand.borrow().inner().seed(interp)?;
+            let other_seed = other.borrow().inner().seed(interp)?;
+            Ok(interp.convert(this_seed == other_seed))
         }
     } else {
-        Err(Box::new(Fatal::new(
-            interp,
-            "Failed to extract Rust Random from Ruby Random receiver",
-        )))
+        Ok(interp.convert(false))
     }
 }
 
@@ -82,14 +96,7 @@ pub fn bytes(
     rand: Value,
     size: Value,
 ) -> Result<Value, Box<dyn RubyException>> {
-    let rand = if let Ok(rand) = unsafe { Random::try_from_ruby(interp, &rand) } {
-        rand
-    } else {
-        return Err(Box::new(Fatal::new(
-            interp,
-            "Failed to extract Rust Random from Ruby Random receiver",
-        )));
-    };
+    let rand = class::try_get_data::<Random>(interp, &rand)?;
     let size = size.implicitly_convert_to_int()?;
     if let Ok(size) = usize::try_from(size) {
         let mut buf = vec![0; size];
@@ -115,14 +122,7 @@ pub fn rand(
         Int(Int),
         None,
     }
-    let rand = if let Ok(rand) = unsafe { Random::try_from_ruby(interp, &rand) } {
-        rand
-    } else {
-        return Err(Box::new(Fatal::new(
-            interp,
-            "Failed to extract Rust Random from Ruby Random receiver",
-        )));
-    };
+    let rand = class::try_get_data::<Random>(interp, &rand)?;
     let max = if let Some(max) = max {
         if let Ok(max) = max.clone().try_into::<Int>() {
             Max::Int(max)
@@ -172,17 +172,11 @@ pub fn rand(
 }
 
 pub fn seed(interp: &Artichoke, rand: Value) -> Result<Value, Box<dyn RubyException>> {
-    if let Ok(rand) = unsafe { Random::try_from_ruby(interp, &rand) } {
-        let borrow = rand.borrow();
-        let seed = borrow.inner().seed(interp)?;
-        #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
-        Ok(interp.convert(seed as Int))
-    } else {
-        Err(Box::new(Fatal::new(
-            interp,
-            "Failed to extract Rust Random from Ruby Random receiver",
-        )))
-    }
+    let rand = class::try_get_data::<Random>(interp, &rand)?;
+    let borrow = rand.borrow();
+    let seed = borrow.inner().seed(interp)?;
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    Ok(interp.convert(seed as Int))
 }
 
 pub fn new_seed(interp: &Artichoke) -> Result<Value, Box<dyn RubyException>> {
@@ -6,6 +6,7 @@ use crate::def;
 use crate::extn::core::exception;
 use crate::extn::core::random;
 use crate::sys;
+use crate::types::Int;
 use crate::value::Value;
 use crate::{Artichoke, ArtichokeError};
 
@@ -16,13 +17,13 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     let spec = class::Spec::new("Random", None, Some(def::rust_data_free::<random::Random>));
     class::Builder::for_spec(interp, &spec)
         .value_is_rust_object()
-        .add_self_method(
+        .add_class_method(
             "new_seed",
             artichoke_random_self_new_seed,
             sys::mrb_args_req(1),
         )
-        .add_self_method("srand", artichoke_random_self_srand, sys::mrb_args_opt(1))
-        .add_self_method(
+        .add_class_method("srand", artichoke_random_self_srand, sys::mrb_args_opt(1))
+        .add_class_method(
             "urandom",
             artichoke_random_self_urandom,
             sys::mrb_args_req(1),
@@ -47,6 +48,10 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         .and_then(|spec| spec.rclass(interp))
         .ok_or(ArtichokeError::New)?;
     let mrb = borrow.mrb;
+    // `Random::DEFAULT` delegates to the interpreter-global PRNG on `State`,
+    // so `DEFAULT_SEED` reflects the seed the `State` PRNG was created with.
+    #[allow(clippy::cast_possible_wrap)]
+    let default_seed = borrow.prng_seed() as Int;
     unsafe {
         sys::mrb_define_const(
             mrb,
@@ -54,6 +59,12 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
             b"DEFAULT\0".as_ptr() as *const i8,
             default.inner(),
         );
+        sys::mrb_define_const(
+            mrb,
+            rclass,
+            b"DEFAULT_SEED\0".as_ptr() as *const i8,
+            sys::mrb_sys_fixnum_value(default_seed),
+        );
     }
     interp.eval(&include_bytes!("random.rb")[..])?;
     trace!("Patched Random onto interpreter");
@@ -184,3 +195,46 @@ unsafe extern "C" fn artichoke_random_self_urandom(
         Err(exception) => exception::raise(interp, exception),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::types::Int;
+    use crate::value::ValueLike;
+
+    #[test]
+    fn srand_makes_rand_deterministic() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"srand(42); first = rand; srand(42); first == rand")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn srand_returns_old_seed() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"srand(42); srand(7)").expect("eval");
+        assert_eq!(result.try_into::<Int>().expect("convert"), 42);
+    }
+
+    #[test]
+    fn default_seed_const_matches_state_prng_seed() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"Random::DEFAULT_SEED").expect("eval");
+        let default_seed = result.try_into::<Int>().expect("convert");
+        #[allow(clippy::cast_possible_wrap)]
+        let prng_seed = interp.0.borrow().prng_seed() as Int;
+        assert_eq!(default_seed, prng_seed);
+    }
+
+    #[test]
+    fn seeded_randoms_return_the_same_sequence() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"Random.new(42).rand == Random.new(42).rand")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+}
@@ -1,6 +1,12 @@
 use artichoke_core::eval::Eval;
+use artichoke_core::value::Value as _;
 
 use crate::class;
+use crate::convert::Convert;
+use crate::extn::core::exception::{self, Fatal, RangeError, RubyException, TypeError};
+use crate::sys;
+use crate::types::{Int, Ruby};
+use crate::value::Value;
 use crate::{Artichoke, ArtichokeError};
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
@@ -8,6 +14,14 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         return Ok(());
     }
     let spec = class::Spec::new("Range", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method("include?", Range::includes, sys::mrb_args_req(1))
+        .add_method("member?", Range::includes, sys::mrb_args_req(1))
+        .add_method("===", Range::includes, sys::mrb_args_req(1))
+        .add_method("cover?", Range::cover, sys::mrb_args_req(1))
+        .add_method("to_a", Range::to_a, sys::mrb_args_none())
+        .add_method("entries", Range::to_a, sys::mrb_args_none())
+        .define()?;
     interp.0.borrow_mut().def_class::<Range>(spec);
     interp.eval(&include_bytes!("range.rb")[..])?;
     trace!("Patched Range onto interpreter");
@@ -15,3 +29,298 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 }
 
 pub struct Range;
+
+impl Range {
+    unsafe extern "C" fn includes(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let other = Value::new(&interp, other);
+        // `Range#include?` on numeric and `String` endpoints compares against
+        // the endpoints like `Range#cover?` rather than iterating, which
+        // matches MRI on `Float` ranges where `#succ` iteration is undefined.
+        let result = covers(&interp, &value, &other).map(|covered| interp.convert(covered));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn cover(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let other = Value::new(&interp, other);
+        let result = if other.ruby_type() == Ruby::Range {
+            covers_range(&interp, &value, &other)
+        } else {
+            covers(&interp, &value, &other)
+        };
+        let result = result.map(|covered| interp.convert(covered));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn to_a(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = collect(&interp, &value);
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+}
+
+/// Extract a `Range`'s begin, end, and exclusivity.
+fn parts(interp: &Artichoke, range: &Value) -> Result<(Value, Value, bool), Box<dyn RubyException>> {
+    let begin = range
+        .funcall::<Value>("begin", &[], None)
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(interp, "Unable to extract Range begin"))
+        })?;
+    let end = range
+        .funcall::<Value>("end", &[], None)
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(interp, "Unable to extract Range end"))
+        })?;
+    let excl = range
+        .funcall::<bool>("exclude_end?", &[], None)
+        .map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(interp, "Unable to extract Range exclusivity"))
+        })?;
+    Ok((begin, end, excl))
+}
+
+/// Compare two values with `<=>`, returning `None` if the values are not
+/// comparable.
+fn compare(left: &Value, right: &Value) -> Option<Int> {
+    let cmp = left.funcall::<Value>("<=>", &[right.clone()], None).ok()?;
+    cmp.try_into::<Int>().ok()
+}
+
+/// Whether a value falls between a `Range`'s endpoints, using only `<=>`
+/// comparisons.
+fn covers(interp: &Artichoke, range: &Value, other: &Value) -> Result<bool, Box<dyn RubyException>> {
+    let (begin, end, excl) = parts(interp, range)?;
+    let cmp_begin = match compare(&begin, other) {
+        Some(cmp) => cmp,
+        None => return Ok(false),
+    };
+    if cmp_begin > 0 {
+        return Ok(false);
+    }
+    let cmp_end = match compare(other, &end) {
+        Some(cmp) => cmp,
+        None => return Ok(false),
+    };
+    if excl {
+        Ok(cmp_end < 0)
+    } else {
+        Ok(cmp_end <= 0)
+    }
+}
+
+/// Whether a `Range` argument falls entirely within a `Range`'s endpoints.
+fn covers_range(
+    interp: &Artichoke,
+    range: &Value,
+    other: &Value,
+) -> Result<bool, Box<dyn RubyException>> {
+    let (begin, end, excl) = parts(interp, range)?;
+    if excl && compare(&begin, &end) == Some(0) {
+        return Ok(false);
+    }
+    let (other_begin, other_end, other_excl) = parts(interp, other)?;
+    match compare(&begin, &other_begin) {
+        Some(cmp) if cmp <= 0 => {}
+        _ => return Ok(false),
+    }
+    let cmp_end = compare(&end, &other_end);
+    if excl == other_excl {
+        return Ok(cmp_end.map_or(false, |cmp| cmp >= 0));
+    }
+    if excl {
+        return Ok(cmp_end.map_or(false, |cmp| cmp > 0));
+    }
+    if let Some(cmp) = cmp_end {
+        if cmp >= 0 {
+            return Ok(true);
+        }
+    }
+    // A half-open argument may still be covered if its greatest element
+    // compares less than or equal to this `Range`'s end.
+    let other_max = match other.funcall::<Value>("max", &[], None) {
+        Ok(max) => max,
+        Err(_) => return Ok(false),
+    };
+    if other_max.is_nil() {
+        return Ok(false);
+    }
+    Ok(compare(&end, &other_max).map_or(false, |cmp| cmp >= 0))
+}
+
+/// Collect a `Range`'s elements into an `Array`.
+fn collect(interp: &Artichoke, range: &Value) -> Result<Value, Box<dyn RubyException>> {
+    let (begin, end, excl) = parts(interp, range)?;
+    if begin.ruby_type() == Ruby::Float {
+        return Err(Box::new(TypeError::new(
+            interp,
+            format!("can't iterate from {}", begin.pretty_name()),
+        )));
+    }
+    if end.ruby_type() == Ruby::Nil {
+        return Err(Box::new(RangeError::new(
+            interp,
+            "cannot convert endless range to an array",
+        )));
+    }
+    if begin.ruby_type() == Ruby::Fixnum && end.ruby_type() == Ruby::Fixnum {
+        let begin = begin
+            .try_into::<Int>()
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(Fatal::new(interp, "Unable to extract Integer Range begin"))
+            })?;
+        let end = end.try_into::<Int>().map_err(|_| -> Box<dyn RubyException> {
+            Box::new(Fatal::new(interp, "Unable to extract Integer Range end"))
+        })?;
+        let mut items = Vec::new();
+        let mut item = begin;
+        while item < end {
+            items.push(interp.convert(item));
+            item += 1;
+        }
+        if !excl && begin <= end {
+            items.push(interp.convert(end));
+        }
+        return Ok(interp.convert(items));
+    }
+    if !begin.respond_to("succ").unwrap_or_default() {
+        return Err(Box::new(TypeError::new(
+            interp,
+            format!("can't iterate from {}", begin.pretty_name()),
+        )));
+    }
+    let mut items = Vec::new();
+    let mut item = begin;
+    loop {
+        let cmp = match compare(&item, &end) {
+            Some(cmp) => cmp,
+            None => break,
+        };
+        if cmp > 0 {
+            break;
+        }
+        if cmp == 0 {
+            if !excl {
+                items.push(item);
+            }
+            break;
+        }
+        items.push(item.clone());
+        item = item
+            .funcall::<Value>("succ", &[], None)
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(Fatal::new(interp, "Unable to iterate Range with succ"))
+            })?;
+    }
+    Ok(interp.convert(items))
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+
+    #[test]
+    fn include_is_endpoint_comparison() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"(1..10).include?(5)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"(1..10).member?(11)").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"(1...10).include?(10)").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        // `Float` membership works without iterating.
+        let result = interp.eval(b"(1..10).include?(5.5)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(b"case 7 when 1..10 then :inside else :outside end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("inside")
+        );
+    }
+
+    #[test]
+    fn cover_uses_comparison_only() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"(1..Float::INFINITY).cover?(1e308)")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"('a'..'z').cover?('yellow')").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"(1..10).cover?(nil)").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"(1..10).cover?(2..5)").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"(1..10).cover?(5..15)").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn to_a_collects_elements() {
+        let interp = crate::interpreter().expect("eval");
+        let result = interp.eval(b"(1..10).to_a.length").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 10);
+        let result = interp.eval(b"(1...4).entries").expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<i64>>().expect("convert"),
+            vec![1, 2, 3]
+        );
+        let result = interp.eval(b"('a'..'e').to_a").expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<&str>>().expect("convert"),
+            vec!["a", "b", "c", "d", "e"]
+        );
+        let result = interp.eval(b"(5..1).to_a").expect("eval");
+        assert_eq!(result.try_into::<Vec<i64>>().expect("convert"), vec![]);
+        let result = interp
+            .eval(b"begin; (1.0..4.0).to_a; rescue TypeError; :refused; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("refused")
+        );
+    }
+
+    #[test]
+    fn step_iterates_by_increments() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(b"items = []; (1..10).step(3) { |item| items << item }; items")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<i64>>().expect("convert"),
+            vec![1, 4, 7, 10]
+        );
+        let result = interp
+            .eval(b"items = []; (1...10).step(3) { |item| items << item }; items")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<i64>>().expect("convert"),
+            vec![1, 4, 7]
+        );
+        let result = interp
+            .eval(b"begin; (1..10).step(0) { |item| item }; rescue ArgumentError; :refused; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("refused")
+        );
+    }
+}
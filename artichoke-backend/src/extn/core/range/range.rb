@@ -3,55 +3,6 @@
 class Range
   include Enumerable
 
-  def cover?(*args)
-    raise ArgumentError, "wrong number of arguments (given #{args.length}, expected 1)" unless args.length == 1
-
-    range_begin = self.begin
-    range_end = self.end
-
-    return false if exclude_end? && (range_begin <=> range_end).zero?
-
-    val = args[0]
-
-    if val.is_a?(Range)
-      val_begin = val.begin
-      val_end = val.end
-
-      cmp_begin = range_begin <=> val_begin
-      return false if cmp_begin.nil? || cmp_begin.positive?
-
-      cmp_end = range_end <=> val_end
-      return cmp_end >= 0 if exclude_end? == val.exclude_end?
-      return cmp_end.positive? if exclude_end?
-      return true if cmp_end >= 0
-
-      val_max = begin
-                  val.max
-                rescue StandardError
-                  nil
-                end
-      return false if val_max.nil?
-
-      return (range_end <=> val_max) >= 0
-    end
-
-    cmp_begin = val <=> range_begin
-    return false if cmp_begin.nil? || cmp_begin.negative?
-
-    max = begin
-            self.max
-          rescue StandardError
-            nil
-          end
-    cmp_max = val <=> max
-    cmp_end = val <=> range_end
-
-    return cmp_end.negative? if exclude_end?
-    return true if cmp_end <= 0
-
-    cmp_max <= 0
-  end
-
   def each(&block)
     return to_enum :each unless block
 
@@ -212,4 +163,30 @@ class Range
       raise NotImplementedError
     end
   end
+
+  def step(n = 1, &block)
+    return to_enum(:step, n) unless block
+
+    raise ArgumentError, "step can't be 0" if n.zero?
+    raise ArgumentError, "step can't be negative" if n.negative?
+
+    val = first
+    if val.is_a?(Numeric)
+      i = 0
+      loop do
+        item = val + (i * n)
+        break if exclude_end? ? item >= self.end : item > self.end
+
+        block.call(item)
+        i += 1
+      end
+    else
+      i = 0
+      each do |item|
+        block.call(item) if (i % n).zero?
+        i += 1
+      end
+    end
+    self
+  end
 end
@@ -689,3 +689,56 @@ impl PartialEq for &dyn RegexpType {
 }
 
 impl Eq for &dyn RegexpType {}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::types::Int;
+    use crate::value::ValueLike;
+    use crate::ArtichokeError;
+
+    #[test]
+    fn regexp_new_matches() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp
+            .eval(br#"Regexp.new("a+").match("aaa")[0]"#)
+            .expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "aaa");
+    }
+
+    #[test]
+    fn match_operator_returns_position() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(br#"Regexp.new("b") =~ "abc""#).expect("eval");
+        assert_eq!(result.try_into::<Int>().expect("convert"), 1);
+        let result = interp.eval(br#"Regexp.new("z") =~ "abc""#).expect("eval");
+        assert!(result.is_nil());
+    }
+
+    #[test]
+    fn source_and_options_roundtrip() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(br#"Regexp.new("a+").source"#).expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "a+");
+        let result = interp
+            .eval(br#"Regexp.new("a+", Regexp::IGNORECASE).options"#)
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<Int>().expect("convert"),
+            super::IGNORECASE
+        );
+    }
+
+    #[test]
+    fn invalid_pattern_raises_regexp_error() {
+        let interp = crate::interpreter().expect("init");
+        let err = interp.eval(br#"Regexp.new("+")"#).unwrap_err();
+        match err {
+            ArtichokeError::Exec(message) => {
+                assert!(message.starts_with("RegexpError"), "got: {}", message)
+            }
+            err => panic!("expected RegexpError, got {:?}", err),
+        }
+    }
+}
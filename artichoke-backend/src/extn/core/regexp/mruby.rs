@@ -18,10 +18,10 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     class::Builder::for_spec(interp, &spec)
         .value_is_rust_object()
         .add_method("initialize", initialize, sys::mrb_args_req_and_opt(1, 2))
-        .add_self_method("compile", compile, sys::mrb_args_rest())
-        .add_self_method("escape", escape, sys::mrb_args_req(1))
-        .add_self_method("quote", escape, sys::mrb_args_req(1))
-        .add_self_method("union", union, sys::mrb_args_rest())
+        .add_class_method("compile", compile, sys::mrb_args_rest())
+        .add_class_method("escape", escape, sys::mrb_args_req(1))
+        .add_class_method("quote", escape, sys::mrb_args_req(1))
+        .add_class_method("union", union, sys::mrb_args_rest())
         .add_method("==", eql, sys::mrb_args_req(1))
         .add_method("===", case_compare, sys::mrb_args_req(1))
         .add_method("=~", match_operator, sys::mrb_args_req(1))
@@ -1,22 +1,38 @@
 use artichoke_core::eval::Eval;
 
 use crate::class;
-use crate::convert::TryConvert;
-use crate::extn::core::exception::{self, ArgumentError, Fatal};
+use crate::convert::{Convert, TryConvert};
+use crate::extn::core::exception::{self, ArgumentError, Fatal, RubyException};
 use crate::sys;
 use crate::value::{Value, ValueLike};
 use crate::{Artichoke, ArtichokeError};
 
+pub mod encoding;
 mod scan;
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
     if interp.0.borrow().class_spec::<RString>().is_some() {
         return Ok(());
     }
+    // `String#encoding` materializes instances of the `Encoding` class.
+    encoding::init(interp)?;
     let spec = class::Spec::new("String", None, None);
     class::Builder::for_spec(interp, &spec)
+        .add_method("b", RString::b, sys::mrb_args_none())
+        .add_method("encode", RString::encode, sys::mrb_args_req(1))
+        .add_method("encoding", RString::encoding, sys::mrb_args_none())
+        .add_method(
+            "force_encoding",
+            RString::force_encoding,
+            sys::mrb_args_req(1),
+        )
         .add_method("ord", RString::ord, sys::mrb_args_none())
         .add_method("scan", RString::scan, sys::mrb_args_req(1))
+        .add_method(
+            "valid_encoding?",
+            RString::is_valid_encoding,
+            sys::mrb_args_none(),
+        )
         .define()?;
     interp.0.borrow_mut().def_class::<RString>(spec);
     interp.eval(&include_bytes!("string.rb")[..])?;
@@ -28,6 +44,142 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 pub struct RString;
 
 impl RString {
+    /// The [`encoding::Encoding`] of a `String` value.
+    ///
+    /// `String`s with no recorded encoding are UTF-8.
+    fn encoding_of(interp: &Artichoke, value: &Value) -> encoding::Encoding {
+        let id = unsafe { sys::mrb_obj_id(value.inner()) };
+        interp
+            .0
+            .borrow()
+            .string_encoding(id)
+            .unwrap_or(encoding::Encoding::Utf8)
+    }
+
+    fn record_encoding(interp: &Artichoke, value: &Value, enc: encoding::Encoding) {
+        let id = unsafe { sys::mrb_obj_id(value.inner()) };
+        interp.0.borrow_mut().set_string_encoding(id, enc);
+    }
+
+    /// Extract an [`encoding::Encoding`] from an encoding name `String` or an
+    /// `Encoding` instance.
+    fn encoding_arg(
+        interp: &Artichoke,
+        enc: &Value,
+    ) -> Result<encoding::Encoding, Box<dyn RubyException>> {
+        let pretty_name = enc.pretty_name();
+        let name = if let Ok(name) = enc.clone().try_into::<String>() {
+            name
+        } else if let Ok(name) = enc.funcall::<String>("name", &[], None) {
+            name
+        } else {
+            return Err(Box::new(ArgumentError::new(
+                interp,
+                format!("unknown encoding name - {}", pretty_name),
+            )));
+        };
+        encoding::Encoding::from_name(name.as_str()).ok_or_else(|| {
+            Box::new(ArgumentError::new(
+                interp,
+                format!("unknown encoding name - {}", name),
+            )) as Box<dyn RubyException>
+        })
+    }
+
+    unsafe extern "C" fn b(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = value
+            .funcall::<Value>("dup", &[], None)
+            .map_err(|_| Fatal::new(&interp, "failed to dup String receiver"));
+        match result {
+            Ok(dup) => {
+                Self::record_encoding(&interp, &dup, encoding::Encoding::Ascii8bit);
+                dup.inner()
+            }
+            Err(err) => exception::raise(interp, err),
+        }
+    }
+
+    /// `String#encode` returns a copy of the receiver tagged with the given
+    /// encoding. The underlying bytes are not transcoded; see the `encoding`
+    /// module documentation.
+    unsafe extern "C" fn encode(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let enc = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let enc = Value::new(&interp, enc);
+        let result = Self::encoding_arg(&interp, &enc).and_then(|enc| {
+            let dup = value.funcall::<Value>("dup", &[], None).map_err(|_| {
+                Box::new(Fatal::new(&interp, "failed to dup String receiver"))
+                    as Box<dyn RubyException>
+            })?;
+            Self::record_encoding(&interp, &dup, enc);
+            Ok(dup)
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn encoding(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let enc = Self::encoding_of(&interp, &value);
+        match enc.ruby_value(&interp) {
+            Ok(value) => value.inner(),
+            Err(_) => {
+                let exception =
+                    Fatal::new(&interp, "Unable to materialize Encoding for String receiver");
+                exception::raise(interp, exception)
+            }
+        }
+    }
+
+    unsafe extern "C" fn force_encoding(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let enc = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let enc = Value::new(&interp, enc);
+        match Self::encoding_arg(&interp, &enc) {
+            Ok(enc) => {
+                Self::record_encoding(&interp, &value, enc);
+                slf
+            }
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn is_valid_encoding(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let bytes = if let Ok(bytes) = value.clone().try_into::<&[u8]>() {
+            bytes
+        } else {
+            let exception = Fatal::new(&interp, "failed to extract bytes from String receiver");
+            return exception::raise(interp, exception);
+        };
+        let valid = match Self::encoding_of(&interp, &value) {
+            encoding::Encoding::Utf8 => std::str::from_utf8(bytes).is_ok(),
+            // Binary strings have no invalid byte sequences.
+            encoding::Encoding::Ascii8bit => true,
+            encoding::Encoding::UsAscii => bytes.is_ascii(),
+            // A minimal check: UTF-16 code units are two bytes wide.
+            encoding::Encoding::Utf16le => bytes.len() % 2 == 0,
+        };
+        interp.convert(valid).inner()
+    }
+
     unsafe extern "C" fn ord(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
         let interp = unwrap_interpreter!(mrb);
         let value = Value::new(&interp, slf);
@@ -162,6 +314,53 @@ mod tests {
         assert_eq!(result, <Vec<&str>>::new());
     }
 
+    #[test]
+    fn string_encode_tags_copy_with_encoding() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp
+            .eval(br#""hello".encode("ASCII-8BIT").encoding.name"#)
+            .expect("eval");
+        assert_eq!(value.try_into::<String>().expect("convert"), "ASCII-8BIT");
+        // The receiver is untouched.
+        let value = interp
+            .eval(br#"s = "hello"; s.encode("ASCII-8BIT"); s.encoding.name"#)
+            .expect("eval");
+        assert_eq!(value.try_into::<String>().expect("convert"), "UTF-8");
+    }
+
+    #[test]
+    fn string_b_returns_binary_copy() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(br#""hello".b.encoding.name"#).expect("eval");
+        assert_eq!(value.try_into::<String>().expect("convert"), "ASCII-8BIT");
+    }
+
+    #[test]
+    fn string_force_encoding_returns_self() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp
+            .eval(br#"s = "hello"; s.force_encoding("US-ASCII").equal?(s)"#)
+            .expect("eval");
+        assert!(value.try_into::<bool>().expect("convert"));
+        let value = interp.eval(b"s.encoding.name").expect("eval");
+        assert_eq!(value.try_into::<String>().expect("convert"), "US-ASCII");
+        let result = interp.eval(br#""hello".force_encoding("EBCDIC")"#).map(|_| ());
+        assert!(result.is_err(), "unknown encoding names raise ArgumentError");
+    }
+
+    #[test]
+    fn string_valid_encoding() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(br#""hello".valid_encoding?"#).expect("eval");
+        assert!(value.try_into::<bool>().expect("convert"));
+        let value = interp.eval(br#""\xff".valid_encoding?"#).expect("eval");
+        assert!(!value.try_into::<bool>().expect("convert"));
+        let value = interp
+            .eval(br#""\xff".force_encoding("BINARY").valid_encoding?"#)
+            .expect("eval");
+        assert!(value.try_into::<bool>().expect("convert"));
+    }
+
     #[test]
     fn string_unary_minus() {
         let interp = crate::interpreter().expect("init");
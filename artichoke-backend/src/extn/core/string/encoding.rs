@@ -0,0 +1,117 @@
+//! Minimal `Encoding` support for `String`.
+//!
+//! Artichoke `String`s are UTF-8 byte buffers. The `Encoding` class tracks a
+//! per-object label which encoding-aware `String` methods like
+//! `String#force_encoding` manipulate. Transcoding does not modify the
+//! underlying bytes.
+
+use std::borrow::Cow;
+
+use crate::class;
+use crate::convert::Convert;
+use crate::extn::core::exception::{self, Fatal};
+use crate::sys;
+use crate::value::{Value, ValueLike};
+use crate::{Artichoke, ArtichokeError};
+
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    if interp.0.borrow().class_spec::<Encoding>().is_some() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Encoding", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method("initialize", Encoding::initialize, sys::mrb_args_req(1))
+        .add_method("name", Encoding::name_method, sys::mrb_args_none())
+        .add_method("to_s", Encoding::name_method, sys::mrb_args_none())
+        .add_method("==", Encoding::eql, sys::mrb_args_req(1))
+        .define()?;
+    interp.0.borrow_mut().def_class::<Encoding>(spec);
+    trace!("Patched Encoding onto interpreter");
+    Ok(())
+}
+
+/// Encodings supported by Artichoke `String`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    Utf8,
+    Ascii8bit,
+    UsAscii,
+    Utf16le,
+}
+
+impl Encoding {
+    /// Canonical Ruby name of the encoding.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "UTF-8",
+            Self::Ascii8bit => "ASCII-8BIT",
+            Self::UsAscii => "US-ASCII",
+            Self::Utf16le => "UTF-16LE",
+        }
+    }
+
+    /// Look up an `Encoding` by its Ruby name or a well-known alias.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "UTF-8" => Some(Self::Utf8),
+            "ASCII-8BIT" | "BINARY" => Some(Self::Ascii8bit),
+            "US-ASCII" | "ASCII" => Some(Self::UsAscii),
+            "UTF-16LE" => Some(Self::Utf16le),
+            _ => None,
+        }
+    }
+
+    /// Materialize this `Encoding` as a Ruby `Encoding` instance.
+    pub fn ruby_value(self, interp: &Artichoke) -> Result<Value, ArtichokeError> {
+        let spec = interp
+            .0
+            .borrow()
+            .class_spec::<Self>()
+            .cloned()
+            .ok_or(ArtichokeError::NotDefined(Cow::Borrowed("Encoding")))?;
+        let name = interp.convert(self.name());
+        spec.new_instance(interp, &[name])
+            .ok_or(ArtichokeError::New)
+    }
+
+    unsafe extern "C" fn initialize(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let name = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let sym = interp.0.borrow_mut().sym_intern(&b"@name"[..]);
+        sys::mrb_iv_set(mrb, slf, sym, name);
+        slf
+    }
+
+    unsafe extern "C" fn name_method(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let sym = interp.0.borrow_mut().sym_intern(&b"@name"[..]);
+        sys::mrb_iv_get(mrb, slf, sym)
+    }
+
+    unsafe extern "C" fn eql(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        let other = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let this = Value::new(&interp, slf);
+        let other = Value::new(&interp, other);
+        let result = this
+            .funcall::<String>("name", &[], None)
+            .map_err(|_| Fatal::new(&interp, "Unable to extract name from Encoding receiver"));
+        match result {
+            Ok(name) => {
+                let eql = other
+                    .funcall::<String>("name", &[], None)
+                    .map(|other| name == other)
+                    .unwrap_or_default();
+                interp.convert(eql).inner()
+            }
+            Err(err) => exception::raise(interp, err),
+        }
+    }
+}
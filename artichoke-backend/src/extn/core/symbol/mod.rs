@@ -1,6 +1,12 @@
 use artichoke_core::eval::Eval;
+use std::convert::TryFrom;
 
 use crate::class;
+use crate::convert::Convert;
+use crate::extn::core::exception::{self, Fatal, RubyException};
+use crate::sys;
+use crate::types::Int;
+use crate::value::{Value, ValueLike};
 use crate::{Artichoke, ArtichokeError};
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
@@ -8,6 +14,14 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
         return Ok(());
     }
     let spec = class::Spec::new("Symbol", None, None);
+    class::Builder::for_spec(interp, &spec)
+        .add_method("to_s", Symbol::to_s, sys::mrb_args_none())
+        .add_method("id2name", Symbol::to_s, sys::mrb_args_none())
+        .add_method("length", Symbol::length, sys::mrb_args_none())
+        .add_method("upcase", Symbol::upcase, sys::mrb_args_none())
+        .add_method("downcase", Symbol::downcase, sys::mrb_args_none())
+        .add_method("inspect", Symbol::inspect, sys::mrb_args_none())
+        .define()?;
     interp.0.borrow_mut().def_class::<Symbol>(spec);
     interp.eval(&include_bytes!("symbol.rb")[..])?;
     trace!("Patched Symbol onto interpreter");
@@ -15,3 +29,196 @@ pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
 }
 
 pub struct Symbol;
+
+impl Symbol {
+    /// Extract the byte contents of a `Symbol` receiver.
+    fn name(interp: &Artichoke, value: Value) -> Result<String, Box<dyn RubyException>> {
+        value.try_into::<String>().map_err(|_| {
+            Box::new(Fatal::new(
+                interp,
+                "Failed to extract name from Ruby Symbol receiver",
+            )) as Box<dyn RubyException>
+        })
+    }
+
+    /// Intern a symbol name and wrap it in a `Symbol` [`Value`].
+    ///
+    /// The name is also interned in the [`State`](crate::state::State) symbol
+    /// cache so derived symbols participate in symbol lookups from Rust.
+    fn intern(interp: &Artichoke, name: String) -> Value {
+        let mrb = interp.0.borrow().mrb;
+        interp.0.borrow_mut().sym_intern(name.clone().into_bytes());
+        let value = unsafe { sys::mrb_sys_new_symbol(mrb, name.as_ptr() as *const i8, name.len()) };
+        Value::new(interp, value)
+    }
+
+    pub unsafe extern "C" fn to_s(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = Self::name(&interp, value).map(|name| interp.convert(name));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn length(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = Self::name(&interp, value).and_then(|name| {
+            // `Symbol#length` counts characters, not bytes.
+            Int::try_from(name.chars().count())
+                .map(|length| interp.convert(length))
+                .map_err(|_| {
+                    Box::new(Fatal::new(
+                        &interp,
+                        "Symbol length does not fit in Integer max",
+                    )) as Box<dyn RubyException>
+                })
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn upcase(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result =
+            Self::name(&interp, value).map(|name| Self::intern(&interp, name.to_uppercase()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn downcase(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result =
+            Self::name(&interp, value).map(|name| Self::intern(&interp, name.to_lowercase()));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn inspect(
+        mrb: *mut sys::mrb_state,
+        slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, slf);
+        let result = Self::name(&interp, value).map(|name| {
+            if Self::is_ident(name.as_str()) {
+                interp.convert(format!(":{}", name))
+            } else {
+                interp.convert(format!(":{:?}", name))
+            }
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    /// Whether a symbol name can be inspected without quoting.
+    ///
+    /// This check is conservative: any name that is not a plain identifier,
+    /// constant, ivar/gvar/cvar reference, or operator method is quoted.
+    fn is_ident(name: &str) -> bool {
+        const OPERATORS: &[&str] = &[
+            "+", "-", "*", "/", "%", "**", "==", "!=", "<", "<=", ">", ">=", "<=>", "===", "=~",
+            "!~", "!", "~", "<<", ">>", "&", "|", "^", "[]", "[]=", "+@", "-@",
+        ];
+        if OPERATORS.contains(&name) {
+            return true;
+        }
+        let name = if name.starts_with("@@") {
+            &name[2..]
+        } else if name.starts_with('@') || name.starts_with('$') {
+            &name[1..]
+        } else {
+            name
+        };
+        let name = if name.ends_with('?') || name.ends_with('!') || name.ends_with('=') {
+            &name[..name.len() - 1]
+        } else {
+            name
+        };
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(head) if head.is_ascii_alphabetic() || head == '_' => {
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::types::Int;
+    use crate::value::ValueLike;
+
+    #[test]
+    fn to_proc_sends_symbol() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b"[:a, :b].map(&:to_s)").expect("eval");
+        assert_eq!(
+            result.try_into::<Vec<String>>().expect("convert"),
+            vec![String::from("a"), String::from("b")]
+        );
+    }
+
+    #[test]
+    fn to_s_and_length() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b":artichoke.to_s").expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "artichoke");
+        let result = interp.eval(b":artichoke.id2name").expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "artichoke");
+        let result = interp.eval(b":artichoke.length").expect("eval");
+        assert_eq!(result.try_into::<Int>().expect("convert"), 9);
+    }
+
+    #[test]
+    fn upcase_downcase_roundtrip() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b":artichoke.upcase.to_s").expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), "ARTICHOKE");
+        let result = interp
+            .eval(b":ARTICHOKE.downcase == :artichoke")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn inspect_quotes_non_identifiers() {
+        let interp = crate::interpreter().expect("init");
+        let result = interp.eval(b":artichoke.inspect").expect("eval");
+        assert_eq!(result.try_into::<String>().expect("convert"), ":artichoke");
+        let result = interp.eval(br#":"spaced out".inspect"#).expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            r#":"spaced out""#
+        );
+    }
+}
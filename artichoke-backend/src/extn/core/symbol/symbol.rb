@@ -40,10 +40,6 @@ class Symbol
     to_s.casecmp?(other.to_s)
   end
 
-  def downcase
-    to_s.downcase.intern
-  end
-
   def empty?
     self == :''
   end
@@ -52,10 +48,6 @@ class Symbol
     raise NotImplementedError, 'Artichoke does not have Encoding support'
   end
 
-  def length
-    to_s.length
-  end
-
   def match(*args)
     to_s.match(*args)
   end
@@ -80,10 +72,6 @@ class Symbol
     self
   end
 
-  def upcase
-    to_s.upcase.intern
-  end
-
   alias intern to_sym
   alias next succ
   alias size length
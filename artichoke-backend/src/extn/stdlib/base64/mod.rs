@@ -0,0 +1,339 @@
+//! [Base64](https://ruby-doc.org/stdlib-2.6.3/libdoc/base64/rdoc/Base64.html)
+//!
+//! Encoding and decoding delegate to the [`base64`] crate rather than a pure
+//! Ruby implementation. `Base64.encode64` emits RFC 2045 MIME base64 with a
+//! newline after every 60 encoded characters like MRI; the `strict_` variants
+//! implement RFC 4648 and the `urlsafe_` variants use the URL-safe alphabet.
+
+use artichoke_core::file::File;
+use artichoke_core::load::LoadSources;
+use artichoke_core::value::Value as _;
+
+use crate::convert::Convert;
+use crate::extn::core::exception::{self, ArgumentError, Fatal, RubyException, TypeError};
+use crate::module;
+use crate::sys;
+use crate::value::Value;
+use crate::{Artichoke, ArtichokeError};
+
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    interp.def_file_for_type::<Base64>(b"base64.rb")?;
+    Ok(())
+}
+
+pub struct Base64;
+
+impl File for Base64 {
+    type Artichoke = Artichoke;
+
+    fn require(interp: &Artichoke) -> Result<(), ArtichokeError> {
+        if interp.0.borrow().module_spec::<Base64>().is_some() {
+            return Ok(());
+        }
+        let spec = module::Spec::new("Base64", None);
+        module::Builder::for_spec(interp, &spec)
+            .add_module_method("encode64", Base64::encode64, sys::mrb_args_req(1))
+            .add_module_method("decode64", Base64::decode64, sys::mrb_args_req(1))
+            .add_module_method(
+                "strict_encode64",
+                Base64::strict_encode64,
+                sys::mrb_args_req(1),
+            )
+            .add_module_method(
+                "strict_decode64",
+                Base64::strict_decode64,
+                sys::mrb_args_req(1),
+            )
+            .add_module_method(
+                "urlsafe_encode64",
+                Base64::urlsafe_encode64,
+                sys::mrb_args_req_and_opt(1, 1),
+            )
+            .add_module_method(
+                "urlsafe_decode64",
+                Base64::urlsafe_decode64,
+                sys::mrb_args_req(1),
+            )
+            .define()?;
+        interp.0.borrow_mut().def_module::<Base64>(spec);
+        trace!("Patched Base64 onto interpreter");
+        Ok(())
+    }
+}
+
+impl Base64 {
+    fn binary_arg(interp: &Artichoke, value: &Value) -> Result<Vec<u8>, Box<dyn RubyException>> {
+        value
+            .clone()
+            .try_into::<Vec<u8>>()
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(TypeError::new(
+                    interp,
+                    format!(
+                        "no implicit conversion of {} into String",
+                        value.pretty_name()
+                    ),
+                ))
+            })
+    }
+
+    /// Extract the `padding:` option from the trailing options `Hash` of
+    /// `Base64.urlsafe_encode64`.
+    fn padding_option(interp: &Artichoke, opts: &Value) -> Result<bool, Box<dyn RubyException>> {
+        let pairs = opts
+            .clone()
+            .try_into::<Vec<(Value, Value)>>()
+            .map_err(|_| {
+                TypeError::new(
+                    interp,
+                    format!("no implicit conversion of {} into Hash", opts.pretty_name()),
+                )
+            })?;
+        let mut padding = true;
+        for (key, value) in pairs {
+            let key = key
+                .funcall::<String>("to_s", &[], None)
+                .map_err(|_| Fatal::new(interp, "Failed to convert Hash key to String"))?;
+            if key == "padding" {
+                padding = value.try_into::<bool>().unwrap_or_default();
+            } else {
+                return Err(Box::new(ArgumentError::new(
+                    interp,
+                    format!("unknown keyword: {}", key),
+                )));
+            }
+        }
+        Ok(padding)
+    }
+
+    unsafe extern "C" fn encode64(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::binary_arg(&interp, &value).map(|bin| {
+            let encoded = base64::encode_config(&bin, base64::STANDARD);
+            let mut lines = Vec::with_capacity(encoded.len() + encoded.len() / 60 + 1);
+            for chunk in encoded.as_bytes().chunks(60) {
+                lines.extend_from_slice(chunk);
+                lines.push(b'\n');
+            }
+            interp.convert(lines)
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn decode64(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::binary_arg(&interp, &value).and_then(|bin| {
+            // `encode64` output contains newlines, which are not part of the
+            // base64 alphabet. Strip line breaks before decoding.
+            let cleaned = bin
+                .into_iter()
+                .filter(|&byte| byte != b'\n' && byte != b'\r')
+                .collect::<Vec<u8>>();
+            base64::decode_config(&cleaned, base64::STANDARD)
+                .map(|decoded| interp.convert(decoded))
+                .map_err(|_| -> Box<dyn RubyException> {
+                    Box::new(ArgumentError::new(&interp, "invalid base64"))
+                })
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn strict_encode64(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::binary_arg(&interp, &value)
+            .map(|bin| interp.convert(base64::encode_config(&bin, base64::STANDARD)));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn strict_decode64(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::binary_arg(&interp, &value).and_then(|bin| {
+            base64::decode_config(&bin, base64::STANDARD)
+                .map(|decoded| interp.convert(decoded))
+                .map_err(|_| -> Box<dyn RubyException> {
+                    Box::new(ArgumentError::new(&interp, "invalid base64"))
+                })
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn urlsafe_encode64(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let (value, opts) = mrb_get_args!(mrb, required = 1, optional = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let opts = opts.map(|opts| Value::new(&interp, opts));
+        let result = Self::binary_arg(&interp, &value).and_then(|bin| {
+            let padding = if let Some(ref opts) = opts {
+                Self::padding_option(&interp, opts)?
+            } else {
+                true
+            };
+            let config = if padding {
+                base64::URL_SAFE
+            } else {
+                base64::URL_SAFE_NO_PAD
+            };
+            Ok(interp.convert(base64::encode_config(&bin, config)))
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    unsafe extern "C" fn urlsafe_decode64(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = Self::binary_arg(&interp, &value).and_then(|mut bin| {
+            // MRI accepts unpadded input: pad to a multiple of four before
+            // decoding.
+            while bin.len() % 4 != 0 {
+                bin.push(b'=');
+            }
+            base64::decode_config(&bin, base64::URL_SAFE)
+                .map(|decoded| interp.convert(decoded))
+                .map_err(|_| -> Box<dyn RubyException> {
+                    Box::new(ArgumentError::new(&interp, "invalid base64"))
+                })
+        });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+    use quickcheck_macros::quickcheck;
+
+    use crate::convert::Convert;
+    use crate::value::Value;
+
+    #[test]
+    fn encode64_wraps_lines_at_60_characters() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'base64'").expect("require");
+        let result = interp
+            .eval(br#"Base64.encode64('Man')"#)
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("TWFu\n")
+        );
+        let result = interp
+            .eval(br#"Base64.encode64('a' * 60).split("\n").map(&:length)"#)
+            .expect("eval");
+        assert_eq!(result.try_into::<Vec<i64>>().expect("convert"), vec![60, 20]);
+    }
+
+    #[test]
+    fn strict_and_urlsafe_variants() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'base64'").expect("require");
+        let result = interp
+            .eval(br#"Base64.strict_encode64('Man')"#)
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("TWFu")
+        );
+        let result = interp
+            .eval(b"Base64.urlsafe_encode64(\"\\xFB\\xEF\\xBE\")")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("----")
+        );
+        let result = interp
+            .eval(b"Base64.urlsafe_encode64('a', padding: false)")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("YQ")
+        );
+        let result = interp
+            .eval(b"Base64.urlsafe_decode64('YQ')")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("a")
+        );
+    }
+
+    #[test]
+    fn invalid_base64_raises_argument_error() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'base64'").expect("require");
+        let result = interp
+            .eval(b"begin; Base64.strict_decode64('not base64!'); :no_exception; rescue ArgumentError; :exception; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("exception")
+        );
+        let result = interp
+            .eval(b"begin; Base64.decode64('!!!!'); :no_exception; rescue ArgumentError; :exception; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("exception")
+        );
+    }
+
+    #[quickcheck]
+    fn decode64_round_trips_encode64(bytes: Vec<u8>) -> bool {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'base64'").expect("require");
+        let base64_mod = interp.eval(b"Base64").expect("eval");
+        let value = interp.convert(bytes.clone());
+        let encoded = base64_mod
+            .funcall::<Value>("encode64", &[value], None)
+            .expect("encode64");
+        let decoded = base64_mod
+            .funcall::<Vec<u8>>("decode64", &[encoded], None)
+            .expect("decode64");
+        decoded == bytes
+    }
+}
@@ -0,0 +1,31 @@
+use artichoke_core::load::LoadSources;
+
+use crate::{Artichoke, ArtichokeError};
+
+#[cfg(feature = "artichoke-serde")]
+mod native;
+
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    interp.def_rb_source_file(b"json.rb", &include_bytes!("../json.rb")[..])?;
+    interp.def_rb_source_file(b"json/common.rb", &include_bytes!("common.rb")[..])?;
+    interp.def_rb_source_file(
+        b"json/generic_object.rb",
+        &include_bytes!("generic_object.rb")[..],
+    )?;
+    interp.def_rb_source_file(b"json/version.rb", &include_bytes!("version.rb")[..])?;
+    interp.def_rb_source_file(b"json/pure.rb", &include_bytes!("pure.rb")[..])?;
+    interp.def_rb_source_file(
+        b"json/pure/generator.rb",
+        &include_bytes!("pure/generator.rb")[..],
+    )?;
+    interp.def_rb_source_file(
+        b"json/pure/parser.rb",
+        &include_bytes!("pure/parser.rb")[..],
+    )?;
+    // The native entrypoints layer on top of the vendored gem sources, so the
+    // Rust-backed require hook must be registered after the pure Ruby sources
+    // are written to the virtual filesystem.
+    #[cfg(feature = "artichoke-serde")]
+    interp.def_file_for_type::<native::Json>(b"json.rb")?;
+    Ok(())
+}
@@ -0,0 +1,359 @@
+//! Native `serde_json`-backed implementations of the `JSON` module
+//! entrypoints.
+//!
+//! `JSON.parse` deserializes with [`serde_json::from_str`] and maps the
+//! resulting [`serde_json::Value`] into the interpreter with the `Convert`
+//! layer. `JSON.generate` and `JSON.dump` serialize with the
+//! [`serde::Serialize`] impl on [`Value`]. The rest of the gem -- the
+//! `to_json` monkeypatches, `JSON::GenericObject`, and the exception
+//! hierarchy -- continues to come from the vendored pure Ruby sources.
+
+use artichoke_core::eval::Eval;
+use artichoke_core::file::File;
+use artichoke_core::value::Value as _;
+#[cfg(feature = "artichoke-debug")]
+use backtrace::Backtrace;
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+
+use crate::class;
+use crate::convert::Convert;
+use crate::def::EnclosingRubyScope;
+use crate::extn::core::exception::{self, RubyException, TypeError};
+use crate::module;
+use crate::sys;
+use crate::value::Value;
+use crate::{Artichoke, ArtichokeError};
+
+pub struct Json;
+
+impl File for Json {
+    type Artichoke = Artichoke;
+
+    fn require(interp: &Artichoke) -> Result<(), ArtichokeError> {
+        // Load the vendored gem sources first so the `JSON` module, its
+        // exception hierarchy, and the `to_json` monkeypatches are defined,
+        // then swap the entrypoints for the native implementations.
+        interp.eval(&include_bytes!("../json.rb")[..])?;
+        let spec = module::Spec::new("JSON", None);
+        module::Builder::for_spec(interp, &spec)
+            .add_module_method("parse", Self::parse, sys::mrb_args_req(1))
+            .add_module_method("generate", Self::generate, sys::mrb_args_req(1))
+            .add_module_method("dump", Self::generate, sys::mrb_args_req(1))
+            .define()?;
+        Ok(())
+    }
+}
+
+impl Json {
+    pub unsafe extern "C" fn parse(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let json = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let json = Value::new(&interp, json);
+        let result = json
+            .clone()
+            .try_into::<String>()
+            .map_err(|_| -> Box<dyn RubyException> {
+                Box::new(TypeError::new(
+                    &interp,
+                    format!(
+                        "no implicit conversion of {} into String",
+                        json.pretty_name()
+                    ),
+                ))
+            })
+            .and_then(|json| {
+                serde_json::from_str::<serde_json::Value>(json.as_str()).map_err(
+                    |err| -> Box<dyn RubyException> {
+                        Box::new(ParserError::new(&interp, err.to_string()))
+                    },
+                )
+            })
+            .map(|json| interp.convert(json));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+
+    pub unsafe extern "C" fn generate(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let value = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let value = Value::new(&interp, value);
+        let result = serde_json::to_string(&value)
+            .map(|json| interp.convert(json))
+            .map_err(|err| -> Box<dyn RubyException> {
+                Box::new(GeneratorError::new(&interp, err.to_string()))
+            });
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => exception::raise(interp, exception),
+        }
+    }
+}
+
+/// Resolve the [`RClass *`](sys::RClass) of an exception class nested under
+/// the `JSON` module.
+///
+/// The `JSON` exception hierarchy is defined by the vendored Ruby sources
+/// rather than Rust-registered class specs, so the class must be looked up by
+/// name each time.
+fn json_exception_rclass(interp: &Artichoke, name: &str) -> Option<*mut sys::RClass> {
+    let scope = module::Spec::new("JSON", None);
+    let spec = class::Spec::new(
+        String::from(name),
+        Some(EnclosingRubyScope::module(&scope)),
+        None,
+    );
+    spec.rclass(interp)
+}
+
+pub struct ParserError {
+    interp: Artichoke,
+    message: Cow<'static, [u8]>,
+    #[cfg(feature = "artichoke-debug")]
+    backtrace: Backtrace,
+}
+
+impl ParserError {
+    pub fn new<S>(interp: &Artichoke, message: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        let message = match message.into() {
+            Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+            Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+        };
+        Self {
+            interp: interp.clone(),
+            message,
+            #[cfg(feature = "artichoke-debug")]
+            backtrace: Backtrace::new(),
+        }
+    }
+}
+
+impl From<ParserError> for Box<dyn RubyException> {
+    fn from(exception: ParserError) -> Box<dyn RubyException> {
+        Box::new(exception)
+    }
+}
+
+impl RubyException for ParserError {
+    fn message(&self) -> &[u8] {
+        self.message.as_ref()
+    }
+
+    fn name(&self) -> String {
+        String::from("JSON::ParserError")
+    }
+
+    fn rclass(&self) -> Option<*mut sys::RClass> {
+        json_exception_rclass(&self.interp, "ParserError")
+    }
+}
+
+impl fmt::Debug for ParserError {
+    #[cfg(feature = "artichoke-debug")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{} ({})", classname, message)?;
+        write!(f, "\n{:?}", self.backtrace)
+    }
+
+    #[cfg(not(feature = "artichoke-debug"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{} ({})", classname, message)
+    }
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{}: {}", classname, message)
+    }
+}
+
+impl PartialEq for ParserError {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.message() == other.message()
+    }
+}
+
+impl PartialEq<Box<dyn RubyException>> for ParserError {
+    fn eq(&self, other: &Box<dyn RubyException>) -> bool {
+        self.name() == other.name() && self.message() == other.message()
+    }
+}
+
+impl error::Error for ParserError {
+    fn description(&self) -> &str {
+        "Ruby Exception: JSON::ParserError"
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        None
+    }
+}
+
+pub struct GeneratorError {
+    interp: Artichoke,
+    message: Cow<'static, [u8]>,
+    #[cfg(feature = "artichoke-debug")]
+    backtrace: Backtrace,
+}
+
+impl GeneratorError {
+    pub fn new<S>(interp: &Artichoke, message: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        let message = match message.into() {
+            Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+            Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+        };
+        Self {
+            interp: interp.clone(),
+            message,
+            #[cfg(feature = "artichoke-debug")]
+            backtrace: Backtrace::new(),
+        }
+    }
+}
+
+impl From<GeneratorError> for Box<dyn RubyException> {
+    fn from(exception: GeneratorError) -> Box<dyn RubyException> {
+        Box::new(exception)
+    }
+}
+
+impl RubyException for GeneratorError {
+    fn message(&self) -> &[u8] {
+        self.message.as_ref()
+    }
+
+    fn name(&self) -> String {
+        String::from("JSON::GeneratorError")
+    }
+
+    fn rclass(&self) -> Option<*mut sys::RClass> {
+        json_exception_rclass(&self.interp, "GeneratorError")
+    }
+}
+
+impl fmt::Debug for GeneratorError {
+    #[cfg(feature = "artichoke-debug")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{} ({})", classname, message)?;
+        write!(f, "\n{:?}", self.backtrace)
+    }
+
+    #[cfg(not(feature = "artichoke-debug"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{} ({})", classname, message)
+    }
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{}: {}", classname, message)
+    }
+}
+
+impl PartialEq for GeneratorError {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.message() == other.message()
+    }
+}
+
+impl PartialEq<Box<dyn RubyException>> for GeneratorError {
+    fn eq(&self, other: &Box<dyn RubyException>) -> bool {
+        self.name() == other.name() && self.message() == other.message()
+    }
+}
+
+impl error::Error for GeneratorError {
+    fn description(&self) -> &str {
+        "Ruby Exception: JSON::GeneratorError"
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+
+    #[test]
+    fn parse_returns_ruby_values() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'json'").expect("require");
+        let result = interp
+            .eval(br#"JSON.parse('{"a":1}')["a"]"#)
+            .expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 1);
+    }
+
+    #[test]
+    fn generate_round_trips_through_parse() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'json'").expect("require");
+        let result = interp
+            .eval(br#"JSON.parse(JSON.generate('key' => [1, 2, 3])) == { 'key' => [1, 2, 3] }"#)
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp
+            .eval(br#"JSON.dump([1, 2, 3])"#)
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("[1,2,3]")
+        );
+    }
+
+    #[test]
+    fn invalid_document_raises_parser_error() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'json'").expect("require");
+        let result = interp
+            .eval(b"begin; JSON.parse('{'); :no_exception; rescue JSON::ParserError; :exception; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("exception")
+        );
+    }
+
+    #[test]
+    fn parse_requires_a_string() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'json'").expect("require");
+        let result = interp
+            .eval(b"begin; JSON.parse(17); :no_exception; rescue TypeError; :exception; end")
+            .expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            String::from("exception")
+        );
+    }
+}
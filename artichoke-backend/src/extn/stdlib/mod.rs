@@ -1,5 +1,7 @@
 use crate::{Artichoke, ArtichokeError};
 
+#[cfg(feature = "stdlib-base64")]
+pub mod base64;
 pub mod delegate;
 pub mod forwardable;
 pub mod json;
@@ -9,6 +11,8 @@ pub mod set;
 pub mod strscan;
 
 pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    #[cfg(feature = "stdlib-base64")]
+    base64::init(interp)?;
     delegate::init(interp)?;
     forwardable::init(interp)?;
     json::init(interp)?;
@@ -0,0 +1,896 @@
+//! [`StringScanner`](https://ruby-doc.org/stdlib-2.6.3/libdoc/strscan/rdoc/StringScanner.html)
+//!
+//! `StringScanner` is a hot path in lexers and template engines, so the
+//! scanner is implemented as an [`MRB_TT_DATA`](sys::mrb_vtype::MRB_TT_DATA)
+//! object backed by a Rust struct holding the source bytes and a byte offset
+//! scan pointer. Patterns are `Regexp` objects and matching delegates to the
+//! same [`RegexpType`](crate::extn::core::regexp::RegexpType) backends as the
+//! `Regexp` extension.
+//!
+//! Positions exposed by `#pos` are byte offsets into the source like MRI;
+//! `#charpos` and match length return values are character counts.
+
+use artichoke_core::file::File;
+use artichoke_core::load::LoadSources;
+use artichoke_core::value::Value as _;
+#[cfg(feature = "artichoke-debug")]
+use backtrace::Backtrace;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+use std::str;
+
+use crate::class;
+use crate::convert::{Convert, RustBackedValue};
+use crate::extn::core::exception::{
+    ArgumentError, Fatal, IndexError, RangeError, RubyException, TypeError,
+};
+use crate::extn::core::regexp::Regexp;
+use crate::sys;
+use crate::types::{Int, Ruby};
+use crate::value::Value;
+use crate::{Artichoke, ArtichokeError};
+
+pub mod mruby;
+
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    interp.def_file_for_type::<StringScanner>(b"strscan.rb")?;
+    Ok(())
+}
+
+impl File for StringScanner {
+    type Artichoke = Artichoke;
+
+    fn require(interp: &Artichoke) -> Result<(), ArtichokeError> {
+        mruby::init(interp)
+    }
+}
+
+/// A successful match against the remainder of the source.
+///
+/// Offsets are byte offsets into the full source string. `captures` is the
+/// `MatchData#to_a` equivalent -- the entire match at index zero followed by
+/// the capture groups. `captures` is [`None`] for matches produced by
+/// `#get_byte` and `#getch`, which match a string rather than a pattern.
+struct LastMatch {
+    begin: usize,
+    end: usize,
+    captures: Option<Vec<Option<Vec<u8>>>>,
+    named_captures: HashMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+pub struct StringScanner {
+    string: Vec<u8>,
+    pos: usize,
+    prev_pos: Option<usize>,
+    last_match: Option<LastMatch>,
+}
+
+impl StringScanner {
+    pub fn new(string: Vec<u8>) -> Self {
+        Self {
+            string,
+            pos: 0,
+            prev_pos: None,
+            last_match: None,
+        }
+    }
+
+    fn rest_slice(&self) -> &[u8] {
+        &self.string[self.pos..]
+    }
+
+    fn is_eos(&self) -> bool {
+        self.pos == self.string.len()
+    }
+
+    fn clear_match(&mut self) {
+        self.prev_pos = None;
+        self.last_match = None;
+    }
+}
+
+impl RustBackedValue for StringScanner {
+    fn ruby_type_name() -> &'static str {
+        "StringScanner"
+    }
+}
+
+/// Count the characters in a byte slice.
+///
+/// Sources are not required to be valid UTF-8; invalid byte sequences fall
+/// back to a byte count like mruby's binary strings.
+fn char_len(bytes: &[u8]) -> usize {
+    if let Ok(string) = str::from_utf8(bytes) {
+        string.chars().count()
+    } else {
+        bytes.len()
+    }
+}
+
+fn int_from_len(interp: &Artichoke, len: usize) -> Result<Int, Box<dyn RubyException>> {
+    Int::try_from(len).map_err(|_| -> Box<dyn RubyException> {
+        Box::new(Fatal::new(interp, "length exceeds Integer max"))
+    })
+}
+
+/// Match `pattern` against the unscanned remainder of the source.
+///
+/// On a match, byte offsets are translated from rest-relative to absolute
+/// offsets into the source.
+fn match_rest(
+    interp: &Artichoke,
+    scanner: &StringScanner,
+    pattern: &Value,
+) -> Result<Option<LastMatch>, Box<dyn RubyException>> {
+    let regexp = unsafe { Regexp::try_from_ruby(interp, pattern) }.map_err(|_| {
+        TypeError::new(
+            interp,
+            format!(
+                "wrong argument type {} (expected Regexp)",
+                pattern.pretty_name()
+            ),
+        )
+    })?;
+    let regexp = regexp.borrow();
+    let rest = scanner.rest_slice();
+    if let Some((begin, end)) = regexp.inner().pos(interp, rest, 0)? {
+        let captures = regexp.inner().captures(interp, rest)?;
+        let named_captures = regexp
+            .inner()
+            .named_captures_for_haystack(interp, rest)?
+            .unwrap_or_default();
+        Ok(Some(LastMatch {
+            begin: scanner.pos + begin,
+            end: scanner.pos + end,
+            captures,
+            named_captures,
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Extract a capture from the last match per `MatchData#[]` semantics.
+///
+/// Out of range integer groups return [`None`]; unknown group names raise
+/// `IndexError`.
+fn capture_at(
+    interp: &Artichoke,
+    last_match: &LastMatch,
+    group: &Value,
+) -> Result<Option<Vec<u8>>, Box<dyn RubyException>> {
+    let captures = if let Some(ref captures) = last_match.captures {
+        captures
+    } else {
+        return Err(Box::new(IndexError::new(interp, "index out of range")));
+    };
+    let name = match group.ruby_type() {
+        Ruby::String => Some(group.clone().try_into::<Vec<u8>>().map_err(|_| {
+            Fatal::new(interp, "Failed to convert Ruby String into Rust Vec<u8>")
+        })?),
+        Ruby::Symbol => Some(group.funcall::<Vec<u8>>("to_s", &[], None).map_err(|_| {
+            Fatal::new(interp, "Failed to convert Ruby Symbol into Rust Vec<u8>")
+        })?),
+        Ruby::Nil => {
+            return Err(Box::new(TypeError::new(
+                interp,
+                "no implicit conversion from nil to integer",
+            )))
+        }
+        _ => None,
+    };
+    if let Some(name) = name {
+        if let Some(capture) = last_match.named_captures.get(&name) {
+            Ok(capture.clone())
+        } else {
+            Err(Box::new(IndexError::new(
+                interp,
+                format!(
+                    "undefined group name reference: {}",
+                    String::from_utf8_lossy(name.as_slice())
+                ),
+            )))
+        }
+    } else {
+        let index = group.implicitly_convert_to_int()?;
+        let index = if index < 0 {
+            let index = index
+                .checked_neg()
+                .and_then(|index| usize::try_from(index).ok());
+            if let Some(index) = index.and_then(|index| captures.len().checked_sub(index)) {
+                index
+            } else {
+                return Ok(None);
+            }
+        } else if let Ok(index) = usize::try_from(index) {
+            index
+        } else {
+            return Ok(None);
+        };
+        Ok(captures.get(index).cloned().unwrap_or_default())
+    }
+}
+
+pub fn initialize(
+    interp: &Artichoke,
+    string: Value,
+    into: Option<sys::mrb_value>,
+) -> Result<Value, Box<dyn RubyException>> {
+    let string = string.clone().try_into::<Vec<u8>>().map_err(|_| {
+        TypeError::new(
+            interp,
+            format!("no implicit conversion of {} into String", string.pretty_name()),
+        )
+    })?;
+    let scanner = StringScanner::new(string);
+    let result = unsafe { scanner.try_into_ruby(interp, into) }.map_err(|_| {
+        Fatal::new(
+            interp,
+            "Unable to initialize Ruby StringScanner with Rust StringScanner",
+        )
+    })?;
+    Ok(result)
+}
+
+pub fn concat(
+    interp: &Artichoke,
+    scanner: Value,
+    other: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let other = other.clone().try_into::<Vec<u8>>().map_err(|_| {
+        TypeError::new(
+            interp,
+            format!("no implicit conversion of {} into String", other.pretty_name()),
+        )
+    })?;
+    data.borrow_mut().string.extend(other);
+    Ok(scanner)
+}
+
+pub fn element_reference(
+    interp: &Artichoke,
+    scanner: Value,
+    group: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    if let Some(ref last_match) = borrow.last_match {
+        let capture = capture_at(interp, last_match, &group)?;
+        Ok(interp.convert(capture.map(|capture| interp.convert(capture))))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn is_beginning_of_line(
+    interp: &Artichoke,
+    scanner: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    let result = borrow.pos == 0 || borrow.string[borrow.pos - 1] == b'\n';
+    Ok(interp.convert(result))
+}
+
+pub fn captures(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    let captures = borrow
+        .last_match
+        .as_ref()
+        .and_then(|last_match| last_match.captures.as_ref());
+    if let Some(captures) = captures {
+        let captures = captures
+            .iter()
+            .skip(1)
+            .map(|capture| interp.convert(capture.clone().map(|capture| interp.convert(capture))))
+            .collect::<Vec<Value>>();
+        Ok(interp.convert(captures))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn charpos(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    let charpos = char_len(&borrow.string[..borrow.pos]);
+    Ok(interp.convert(int_from_len(interp, charpos)?))
+}
+
+pub fn set_charpos(
+    interp: &Artichoke,
+    scanner: Value,
+    pointer: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    let pointer = pointer.implicitly_convert_to_int()?;
+    let len = char_len(borrow.string.as_slice());
+    let charpos = if pointer < 0 {
+        let offset = pointer
+            .checked_neg()
+            .and_then(|pointer| usize::try_from(pointer).ok());
+        offset
+            .and_then(|offset| len.checked_sub(offset))
+            .ok_or_else(|| RangeError::new(interp, "index out of range"))?
+    } else {
+        let charpos = usize::try_from(pointer)
+            .map_err(|_| RangeError::new(interp, "index out of range"))?;
+        if charpos > len {
+            return Err(Box::new(RangeError::new(interp, "index out of range")));
+        }
+        charpos
+    };
+    // Translate the character index back into a byte offset.
+    let byte_pos = if let Ok(string) = str::from_utf8(borrow.string.as_slice()) {
+        string
+            .char_indices()
+            .nth(charpos)
+            .map_or_else(|| borrow.string.len(), |(offset, _)| offset)
+    } else {
+        charpos
+    };
+    borrow.pos = byte_pos;
+    Ok(interp.convert(pointer))
+}
+
+pub fn check(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    scan_full(interp, scanner, pattern, false, true)
+}
+
+pub fn check_until(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    if let Some(last_match) = match_rest(interp, &borrow, &pattern)? {
+        let result = interp.convert(borrow.string[borrow.pos..last_match.end].to_vec());
+        borrow.prev_pos = Some(borrow.pos);
+        borrow.last_match = Some(last_match);
+        Ok(result)
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn is_eos(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let result = data.borrow().is_eos();
+    Ok(interp.convert(result))
+}
+
+pub fn exist(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    if let Some(last_match) = match_rest(interp, &borrow, &pattern)? {
+        let len = char_len(&borrow.string[borrow.pos..last_match.end]);
+        Ok(interp.convert(int_from_len(interp, len)?))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn get_byte(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    if borrow.is_eos() {
+        return Ok(interp.convert(None::<Value>));
+    }
+    let begin = borrow.pos;
+    borrow.pos += 1;
+    let end = borrow.pos;
+    borrow.prev_pos = Some(begin);
+    borrow.last_match = Some(LastMatch {
+        begin,
+        end,
+        captures: None,
+        named_captures: HashMap::default(),
+    });
+    Ok(interp.convert(borrow.string[begin..end].to_vec()))
+}
+
+pub fn getch(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    if borrow.is_eos() {
+        return Ok(interp.convert(None::<Value>));
+    }
+    let rest = borrow.rest_slice();
+    // Take the next UTF-8 character, or a single byte if the source is not
+    // valid UTF-8 at the scan pointer.
+    let max = usize::min(4, rest.len());
+    let len = (1..=max)
+        .find(|&len| str::from_utf8(&rest[..len]).is_ok())
+        .unwrap_or(1);
+    let begin = borrow.pos;
+    borrow.pos += len;
+    let end = borrow.pos;
+    borrow.prev_pos = Some(begin);
+    borrow.last_match = Some(LastMatch {
+        begin,
+        end,
+        captures: None,
+        named_captures: HashMap::default(),
+    });
+    Ok(interp.convert(borrow.string[begin..end].to_vec()))
+}
+
+pub fn inspect(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    if borrow.is_eos() {
+        return Ok(interp.convert("#<StringScanner fin>"));
+    }
+    let string = String::from_utf8_lossy(borrow.string.as_slice());
+    let charpos = char_len(&borrow.string[..borrow.pos]);
+    let len = char_len(borrow.string.as_slice());
+    let before = string
+        .chars()
+        .take(charpos)
+        .collect::<Vec<char>>()
+        .iter()
+        .rev()
+        .take(5)
+        .rev()
+        .collect::<String>();
+    let before = if before.is_empty() {
+        String::new()
+    } else if char_len(before.as_bytes()) < 5 {
+        format!(" \"{}\"", before)
+    } else {
+        format!(" \"...{}\"", before)
+    };
+    let after = string.chars().skip(charpos).take(5).collect::<String>();
+    let result = format!(
+        "#<StringScanner {}/{}{} @ \"{}...\">",
+        charpos, len, before, after
+    );
+    Ok(interp.convert(result))
+}
+
+pub fn is_match(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    scan_full(interp, scanner, pattern, false, false)
+}
+
+pub fn matched(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    if let Some(ref last_match) = borrow.last_match {
+        Ok(interp.convert(borrow.string[last_match.begin..last_match.end].to_vec()))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn is_matched(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let result = data.borrow().last_match.is_some();
+    Ok(interp.convert(result))
+}
+
+pub fn matched_size(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    if let Some(ref last_match) = borrow.last_match {
+        let len = char_len(&borrow.string[last_match.begin..last_match.end]);
+        Ok(interp.convert(int_from_len(interp, len)?))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn peek(
+    interp: &Artichoke,
+    scanner: Value,
+    len: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    let len = if let Ok(len) = len.clone().try_into::<Int>() {
+        len
+    } else {
+        return Err(Box::new(RangeError::new(
+            interp,
+            format!("no implicit conversion of {} into Integer", len.pretty_name()),
+        )));
+    };
+    let len = usize::try_from(len)
+        .map_err(|_| ArgumentError::new(interp, "negative string size (or size too big)"))?;
+    let end = usize::min(borrow.pos.saturating_add(len), borrow.string.len());
+    Ok(interp.convert(borrow.string[borrow.pos..end].to_vec()))
+}
+
+pub fn pos(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let pos = data.borrow().pos;
+    Ok(interp.convert(int_from_len(interp, pos)?))
+}
+
+pub fn set_pos(
+    interp: &Artichoke,
+    scanner: Value,
+    pointer: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    let pointer = pointer.implicitly_convert_to_int()?;
+    let len = borrow.string.len();
+    let pos = if pointer < 0 {
+        let offset = pointer
+            .checked_neg()
+            .and_then(|pointer| usize::try_from(pointer).ok());
+        offset
+            .and_then(|offset| len.checked_sub(offset))
+            .ok_or_else(|| RangeError::new(interp, "index out of range"))?
+    } else {
+        let pos =
+            usize::try_from(pointer).map_err(|_| RangeError::new(interp, "index out of range"))?;
+        if pos > len {
+            return Err(Box::new(RangeError::new(interp, "index out of range")));
+        }
+        pos
+    };
+    borrow.pos = pos;
+    Ok(interp.convert(pointer))
+}
+
+pub fn post_match(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    if let Some(ref last_match) = borrow.last_match {
+        Ok(interp.convert(borrow.string[last_match.end..].to_vec()))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn pre_match(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    if let Some(ref last_match) = borrow.last_match {
+        Ok(interp.convert(borrow.string[..last_match.begin].to_vec()))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn reset(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    borrow.pos = 0;
+    borrow.clear_match();
+    Ok(interp.convert(None::<Value>))
+}
+
+pub fn rest(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let rest = data.borrow().rest_slice().to_vec();
+    Ok(interp.convert(rest))
+}
+
+pub fn has_rest(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let result = !data.borrow().is_eos();
+    Ok(interp.convert(result))
+}
+
+pub fn rest_size(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let len = char_len(data.borrow().rest_slice());
+    Ok(interp.convert(int_from_len(interp, len)?))
+}
+
+pub fn scan(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    scan_full(interp, scanner, pattern, true, true)
+}
+
+/// `#scan`, `#check`, `#skip`, and `#match?` all match anchored at the scan
+/// pointer and differ only in whether they advance the pointer and whether
+/// they return the matched string or its length.
+pub fn scan_full(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+    advance: bool,
+    return_string: bool,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    match match_rest(interp, &borrow, &pattern)? {
+        Some(last_match) if last_match.begin == borrow.pos => {
+            let prev_pos = borrow.pos;
+            if advance {
+                borrow.pos = last_match.end;
+            }
+            borrow.prev_pos = Some(prev_pos);
+            let result = if return_string {
+                interp.convert(borrow.string[prev_pos..last_match.end].to_vec())
+            } else {
+                let len = char_len(&borrow.string[prev_pos..last_match.end]);
+                interp.convert(int_from_len(interp, len)?)
+            };
+            borrow.last_match = Some(last_match);
+            Ok(result)
+        }
+        _ => {
+            borrow.clear_match();
+            Ok(interp.convert(None::<Value>))
+        }
+    }
+}
+
+pub fn scan_until(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    if let Some(last_match) = match_rest(interp, &borrow, &pattern)? {
+        let prev_pos = borrow.pos;
+        borrow.pos = last_match.end;
+        borrow.prev_pos = Some(prev_pos);
+        let result = interp.convert(borrow.string[prev_pos..last_match.end].to_vec());
+        borrow.last_match = Some(last_match);
+        Ok(result)
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+/// `#scan_until`-style search without recording the match.
+pub fn search_full(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+    advance: bool,
+    return_string: bool,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    if let Some(last_match) = match_rest(interp, &borrow, &pattern)? {
+        let prev_pos = borrow.pos;
+        if advance {
+            borrow.pos = last_match.end;
+        }
+        borrow.prev_pos = Some(prev_pos);
+        if return_string {
+            Ok(interp.convert(borrow.string[prev_pos..last_match.end].to_vec()))
+        } else {
+            let len = char_len(&borrow.string[prev_pos..last_match.end]);
+            Ok(interp.convert(int_from_len(interp, len)?))
+        }
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn size(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    let captures = borrow
+        .last_match
+        .as_ref()
+        .and_then(|last_match| last_match.captures.as_ref());
+    if let Some(captures) = captures {
+        Ok(interp.convert(int_from_len(interp, captures.len())?))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn skip(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    scan_full(interp, scanner, pattern, true, false)
+}
+
+pub fn skip_until(
+    interp: &Artichoke,
+    scanner: Value,
+    pattern: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    if let Some(last_match) = match_rest(interp, &borrow, &pattern)? {
+        let prev_pos = borrow.pos;
+        borrow.pos = last_match.end;
+        borrow.prev_pos = Some(prev_pos);
+        let len = char_len(&borrow.string[prev_pos..last_match.end]);
+        borrow.last_match = Some(last_match);
+        Ok(interp.convert(int_from_len(interp, len)?))
+    } else {
+        borrow.clear_match();
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub fn string(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let string = data.borrow().string.clone();
+    Ok(interp.convert(string))
+}
+
+pub fn set_string(
+    interp: &Artichoke,
+    scanner: Value,
+    string: Value,
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let bytes = string.clone().try_into::<Vec<u8>>().map_err(|_| {
+        TypeError::new(
+            interp,
+            format!("no implicit conversion of {} into String", string.pretty_name()),
+        )
+    })?;
+    data.borrow_mut().string = bytes;
+    Ok(string)
+}
+
+pub fn terminate(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    borrow.pos = borrow.string.len();
+    borrow.clear_match();
+    Ok(scanner)
+}
+
+pub fn unscan(interp: &Artichoke, scanner: Value) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let mut borrow = data.borrow_mut();
+    if let Some(prev_pos) = borrow.prev_pos {
+        borrow.pos = prev_pos;
+        borrow.clear_match();
+        Ok(interp.convert(None::<Value>))
+    } else {
+        Err(Box::new(ScanError::new(
+            interp,
+            "unscan failed: previous match record not exist",
+        )))
+    }
+}
+
+pub fn values_at(
+    interp: &Artichoke,
+    scanner: Value,
+    groups: &[Value],
+) -> Result<Value, Box<dyn RubyException>> {
+    let data = class::try_get_data::<StringScanner>(interp, &scanner)?;
+    let borrow = data.borrow();
+    if let Some(ref last_match) = borrow.last_match {
+        let mut result = Vec::with_capacity(groups.len());
+        for group in groups {
+            let capture = capture_at(interp, last_match, group)?;
+            result.push(interp.convert(capture.map(|capture| interp.convert(capture))));
+        }
+        Ok(interp.convert(result))
+    } else {
+        Ok(interp.convert(None::<Value>))
+    }
+}
+
+pub struct ScanError {
+    interp: Artichoke,
+    message: Cow<'static, [u8]>,
+    #[cfg(feature = "artichoke-debug")]
+    backtrace: Backtrace,
+}
+
+impl ScanError {
+    pub fn new<S>(interp: &Artichoke, message: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        let message = match message.into() {
+            Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+            Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+        };
+        Self {
+            interp: interp.clone(),
+            message,
+            #[cfg(feature = "artichoke-debug")]
+            backtrace: Backtrace::new(),
+        }
+    }
+}
+
+impl From<ScanError> for Box<dyn RubyException> {
+    fn from(exception: ScanError) -> Box<dyn RubyException> {
+        Box::new(exception)
+    }
+}
+
+impl RubyException for ScanError {
+    fn message(&self) -> &[u8] {
+        self.message.as_ref()
+    }
+
+    fn name(&self) -> String {
+        self.interp
+            .0
+            .borrow()
+            .class_spec::<Self>()
+            .map(|spec| spec.name().to_owned())
+            .unwrap_or_default()
+    }
+
+    fn rclass(&self) -> Option<*mut sys::RClass> {
+        self.interp
+            .0
+            .borrow()
+            .class_spec::<Self>()
+            .and_then(|spec| spec.rclass(&self.interp))
+    }
+}
+
+impl fmt::Debug for ScanError {
+    #[cfg(feature = "artichoke-debug")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{} ({})", classname, message)?;
+        write!(f, "\n{:?}", self.backtrace)
+    }
+
+    #[cfg(not(feature = "artichoke-debug"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{} ({})", classname, message)
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let classname = self.name();
+        let message = String::from_utf8_lossy(self.message());
+        write!(f, "{}: {}", classname, message)
+    }
+}
+
+impl PartialEq for ScanError {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.message() == other.message()
+    }
+}
+
+impl PartialEq<Box<dyn RubyException>> for ScanError {
+    fn eq(&self, other: &Box<dyn RubyException>) -> bool {
+        self.name() == other.name() && self.message() == other.message()
+    }
+}
+
+impl error::Error for ScanError {
+    fn description(&self) -> &str {
+        "Ruby Exception: ScanError"
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        None
+    }
+}
@@ -0,0 +1,833 @@
+use artichoke_core::value::Value as _;
+
+use crate::class;
+use crate::def;
+use crate::extn::core::exception::{self, StandardError};
+use crate::extn::stdlib::strscan;
+use crate::sys;
+use crate::value::Value;
+use crate::{Artichoke, ArtichokeError};
+
+pub fn init(interp: &Artichoke) -> Result<(), ArtichokeError> {
+    if interp
+        .0
+        .borrow()
+        .class_spec::<strscan::StringScanner>()
+        .is_some()
+    {
+        return Ok(());
+    }
+    let standard_error = interp
+        .0
+        .borrow()
+        .class_spec::<StandardError>()
+        .cloned()
+        .ok_or(ArtichokeError::New)?;
+    let scan_error_spec = class::Spec::new("ScanError", None, None);
+    class::Builder::for_spec(interp, &scan_error_spec)
+        .with_super_class(Some(&standard_error))
+        .define()?;
+    interp
+        .0
+        .borrow_mut()
+        .def_class::<strscan::ScanError>(scan_error_spec);
+
+    let spec = class::Spec::new(
+        "StringScanner",
+        None,
+        Some(def::rust_data_free::<strscan::StringScanner>),
+    );
+    class::Builder::for_spec(interp, &spec)
+        .value_is_rust_object()
+        .add_class_method(
+            "must_C_version",
+            artichoke_scanner_self_must_c_version,
+            sys::mrb_args_none(),
+        )
+        .add_method(
+            "initialize",
+            artichoke_scanner_initialize,
+            sys::mrb_args_req(1),
+        )
+        .add_method("<<", artichoke_scanner_concat, sys::mrb_args_req(1))
+        .add_method("concat", artichoke_scanner_concat, sys::mrb_args_req(1))
+        .add_method(
+            "[]",
+            artichoke_scanner_element_reference,
+            sys::mrb_args_req(1),
+        )
+        .add_method(
+            "beginning_of_line?",
+            artichoke_scanner_is_beginning_of_line,
+            sys::mrb_args_none(),
+        )
+        .add_method(
+            "bol?",
+            artichoke_scanner_is_beginning_of_line,
+            sys::mrb_args_none(),
+        )
+        .add_method("captures", artichoke_scanner_captures, sys::mrb_args_none())
+        .add_method("charpos", artichoke_scanner_charpos, sys::mrb_args_none())
+        .add_method(
+            "charpos=",
+            artichoke_scanner_set_charpos,
+            sys::mrb_args_req(1),
+        )
+        .add_method("check", artichoke_scanner_check, sys::mrb_args_req(1))
+        .add_method(
+            "check_until",
+            artichoke_scanner_check_until,
+            sys::mrb_args_req(1),
+        )
+        .add_method("clear", artichoke_scanner_terminate, sys::mrb_args_none())
+        .add_method("empty?", artichoke_scanner_is_eos, sys::mrb_args_none())
+        .add_method("eos?", artichoke_scanner_is_eos, sys::mrb_args_none())
+        .add_method("exist?", artichoke_scanner_exist, sys::mrb_args_req(1))
+        .add_method("get_byte", artichoke_scanner_get_byte, sys::mrb_args_none())
+        .add_method("getbyte", artichoke_scanner_get_byte, sys::mrb_args_none())
+        .add_method("getch", artichoke_scanner_getch, sys::mrb_args_none())
+        .add_method("inspect", artichoke_scanner_inspect, sys::mrb_args_none())
+        .add_method("match?", artichoke_scanner_is_match, sys::mrb_args_req(1))
+        .add_method("matched", artichoke_scanner_matched, sys::mrb_args_none())
+        .add_method(
+            "matched?",
+            artichoke_scanner_is_matched,
+            sys::mrb_args_none(),
+        )
+        .add_method(
+            "matched_size",
+            artichoke_scanner_matched_size,
+            sys::mrb_args_none(),
+        )
+        .add_method("peek", artichoke_scanner_peek, sys::mrb_args_req(1))
+        .add_method("peep", artichoke_scanner_peek, sys::mrb_args_req(1))
+        .add_method("pointer", artichoke_scanner_pos, sys::mrb_args_none())
+        .add_method("pointer=", artichoke_scanner_set_pos, sys::mrb_args_req(1))
+        .add_method("pos", artichoke_scanner_pos, sys::mrb_args_none())
+        .add_method("pos=", artichoke_scanner_set_pos, sys::mrb_args_req(1))
+        .add_method(
+            "post_match",
+            artichoke_scanner_post_match,
+            sys::mrb_args_none(),
+        )
+        .add_method(
+            "pre_match",
+            artichoke_scanner_pre_match,
+            sys::mrb_args_none(),
+        )
+        .add_method("reset", artichoke_scanner_reset, sys::mrb_args_none())
+        .add_method("rest", artichoke_scanner_rest, sys::mrb_args_none())
+        .add_method("rest?", artichoke_scanner_has_rest, sys::mrb_args_none())
+        .add_method(
+            "rest_size",
+            artichoke_scanner_rest_size,
+            sys::mrb_args_none(),
+        )
+        .add_method("restsize", artichoke_scanner_rest_size, sys::mrb_args_none())
+        .add_method("scan", artichoke_scanner_scan, sys::mrb_args_req(1))
+        .add_method("scan_full", artichoke_scanner_scan_full, sys::mrb_args_req(3))
+        .add_method(
+            "scan_until",
+            artichoke_scanner_scan_until,
+            sys::mrb_args_req(1),
+        )
+        .add_method(
+            "search_full",
+            artichoke_scanner_search_full,
+            sys::mrb_args_req(3),
+        )
+        .add_method("size", artichoke_scanner_size, sys::mrb_args_none())
+        .add_method("skip", artichoke_scanner_skip, sys::mrb_args_req(1))
+        .add_method(
+            "skip_until",
+            artichoke_scanner_skip_until,
+            sys::mrb_args_req(1),
+        )
+        .add_method("string", artichoke_scanner_string, sys::mrb_args_none())
+        .add_method("string=", artichoke_scanner_set_string, sys::mrb_args_req(1))
+        .add_method(
+            "terminate",
+            artichoke_scanner_terminate,
+            sys::mrb_args_none(),
+        )
+        .add_method("unscan", artichoke_scanner_unscan, sys::mrb_args_none())
+        .add_method("values_at", artichoke_scanner_values_at, sys::mrb_args_rest())
+        .define()?;
+    interp
+        .0
+        .borrow_mut()
+        .def_class::<strscan::StringScanner>(spec);
+    trace!("Patched StringScanner onto interpreter");
+    Ok(())
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_self_must_c_version(
+    _mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    slf
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_initialize(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let string = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let string = Value::new(&interp, string);
+    let result = strscan::initialize(&interp, string, Some(slf));
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_concat(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let other = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let other = Value::new(&interp, other);
+    let result = strscan::concat(&interp, scanner, other);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_element_reference(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let group = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let group = Value::new(&interp, group);
+    let result = strscan::element_reference(&interp, scanner, group);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_is_beginning_of_line(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::is_beginning_of_line(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_captures(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::captures(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_charpos(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::charpos(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_set_charpos(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pointer = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pointer = Value::new(&interp, pointer);
+    let result = strscan::set_charpos(&interp, scanner, pointer);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_check(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let result = strscan::check(&interp, scanner, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_check_until(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let result = strscan::check_until(&interp, scanner, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_is_eos(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::is_eos(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_exist(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let result = strscan::exist(&interp, scanner, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_get_byte(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::get_byte(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_getch(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::getch(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_inspect(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::inspect(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_is_match(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let result = strscan::is_match(&interp, scanner, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_matched(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::matched(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_is_matched(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::is_matched(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_matched_size(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::matched_size(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_peek(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let len = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let len = Value::new(&interp, len);
+    let result = strscan::peek(&interp, scanner, len);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_pos(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::pos(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_set_pos(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pointer = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pointer = Value::new(&interp, pointer);
+    let result = strscan::set_pos(&interp, scanner, pointer);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_post_match(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::post_match(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_pre_match(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::pre_match(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_reset(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::reset(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_rest(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::rest(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_has_rest(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::has_rest(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_rest_size(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::rest_size(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_scan(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let result = strscan::scan(&interp, scanner, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_scan_full(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (pattern, advance, return_string) = mrb_get_args!(mrb, required = 3);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let advance = Value::new(&interp, advance)
+        .try_into::<bool>()
+        .unwrap_or_default();
+    let return_string = Value::new(&interp, return_string)
+        .try_into::<bool>()
+        .unwrap_or_default();
+    let result = strscan::scan_full(&interp, scanner, pattern, advance, return_string);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_scan_until(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let result = strscan::scan_until(&interp, scanner, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_search_full(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (pattern, advance, return_string) = mrb_get_args!(mrb, required = 3);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let advance = Value::new(&interp, advance)
+        .try_into::<bool>()
+        .unwrap_or_default();
+    let return_string = Value::new(&interp, return_string)
+        .try_into::<bool>()
+        .unwrap_or_default();
+    let result = strscan::search_full(&interp, scanner, pattern, advance, return_string);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_size(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::size(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_skip(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let result = strscan::skip(&interp, scanner, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_skip_until(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let pattern = Value::new(&interp, pattern);
+    let result = strscan::skip_until(&interp, scanner, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_string(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::string(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_set_string(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let string = mrb_get_args!(mrb, required = 1);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let string = Value::new(&interp, string);
+    let result = strscan::set_string(&interp, scanner, string);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_terminate(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::terminate(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_unscan(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let result = strscan::unscan(&interp, scanner);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_scanner_values_at(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    let interp = unwrap_interpreter!(mrb);
+    let scanner = Value::new(&interp, slf);
+    let groups = args
+        .iter()
+        .map(|group| Value::new(&interp, *group))
+        .collect::<Vec<_>>();
+    let result = strscan::values_at(&interp, scanner, groups.as_slice());
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(interp, exception),
+    }
+}
+
+// StringScanner tests from Ruby stdlib docs
+// https://ruby-doc.org/stdlib-2.6.3/libdoc/strscan/rdoc/StringScanner.html
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::load::LoadSources;
+    use artichoke_core::value::Value as _;
+
+    #[test]
+    fn strscan_spec() {
+        let interp = crate::interpreter().expect("init");
+        interp
+            .def_rb_source_file(
+                b"/src/test/strscan_test.rb",
+                &include_bytes!("strscan_test.rb")[..],
+            )
+            .unwrap();
+        interp
+            .eval(&b"require '/src/test/strscan_test.rb'"[..])
+            .unwrap();
+        if let Err(err) = interp.eval(b"spec") {
+            panic!("{}", err);
+        }
+    }
+
+    #[test]
+    fn scanner_is_rust_backed() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'strscan'").expect("require");
+        let result = interp
+            .eval(b"StringScanner.new('a').class == StringScanner.must_C_version")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+
+    #[test]
+    fn scan_error_is_a_standard_error() {
+        let interp = crate::interpreter().expect("init");
+        interp.eval(b"require 'strscan'").expect("require");
+        let result = interp
+            .eval(b"ScanError.ancestors.include?(StandardError)")
+            .expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+    }
+}
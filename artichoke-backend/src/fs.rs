@@ -5,12 +5,20 @@ use path_dedot::ParseDot;
 use std::ffi::OsStr;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::str;
 
 use crate::extn::core::exception::RubyException;
 use crate::{Artichoke, ArtichokeError};
 
 pub const RUBY_LOAD_PATH: &str = "/src/lib";
 
+/// Archive formats supported by [`Filesystem::mount_archive`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// POSIX `ustar` tar archive, as produced by `tar -c`.
+    Tar,
+}
+
 pub type RequireFunc = fn(&Artichoke) -> Result<(), ArtichokeError>;
 
 /// Virtual filesystem that wraps a [`artichoke_vfs`] [`FakeFileSystem`].
@@ -28,33 +36,128 @@ impl Filesystem {
     pub fn new() -> Result<Self, ArtichokeError> {
         let fs = FakeFileSystem::new();
         fs.create_dir_all(RUBY_LOAD_PATH)
-            .map_err(ArtichokeError::Vfs)?;
+            .map_err(ArtichokeError::Io)?;
         Ok(Self { fs })
     }
 
+    /// Bulk-load sources into the virtual filesystem.
+    ///
+    /// Each item is a `(path, content)` pair. Relative paths are stored under
+    /// [`RUBY_LOAD_PATH`] which makes the sources `require`-able by name.
+    /// Intermediate directories are created as needed.
+    ///
+    /// This API is intended for embedders that bundle a library of Ruby
+    /// sources into the binary with `include_bytes!` and load them before any
+    /// user code runs. See [`crate::interpreter_with_preload`].
+    pub fn preload<T>(&self, files: T) -> Result<(), ArtichokeError>
+    where
+        T: IntoIterator<Item = (&'static [u8], &'static [u8])>,
+    {
+        for (path, content) in files {
+            let path = str::from_utf8(path).map_err(|_| {
+                ArtichokeError::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "preload paths must be valid UTF-8",
+                ))
+            })?;
+            let path = Path::new(path);
+            let path = if path.is_relative() {
+                Path::new(RUBY_LOAD_PATH).join(path)
+            } else {
+                path.to_path_buf()
+            };
+            if let Some(parent) = path.parent() {
+                self.create_dir_all(parent)?;
+            }
+            self.write_file(path.as_path(), content)?;
+        }
+        Ok(())
+    }
+
+    /// Mount an in-memory archive of Ruby sources under `mount_point`.
+    ///
+    /// The archive is indexed header by header and file contents are served
+    /// from subslices of `data`, so a source tree can be embedded in the
+    /// binary as a single `include_bytes!` blob instead of one symbol per
+    /// file. Mounting under [`RUBY_LOAD_PATH`] makes the archived sources
+    /// resolvable by `Kernel#require`.
+    ///
+    /// Regular files and directories are mounted. Other entry types — links,
+    /// pax extended headers — are skipped.
+    pub fn mount_archive(
+        &self,
+        data: &'static [u8],
+        format: ArchiveFormat,
+        mount_point: &str,
+    ) -> Result<(), ArtichokeError> {
+        match format {
+            ArchiveFormat::Tar => self.mount_tar(data, mount_point),
+        }
+    }
+
+    fn mount_tar(&self, data: &'static [u8], mount_point: &str) -> Result<(), ArtichokeError> {
+        // Tar archives are a sequence of 512-byte header blocks, each
+        // followed by the entry contents padded to a block boundary. The
+        // archive is terminated by two zero blocks.
+        const BLOCK: usize = 512;
+        let mount_point = Path::new(mount_point);
+        self.create_dir_all(mount_point)?;
+        let mut offset = 0;
+        while offset + BLOCK <= data.len() {
+            let header = &data[offset..offset + BLOCK];
+            if header.iter().all(|&byte| byte == 0) {
+                break;
+            }
+            let name = tar_entry_name(header)?;
+            let size = tar_octal_field(&header[124..136])?;
+            let typeflag = header[156];
+            let contents_start = offset + BLOCK;
+            let contents_end = contents_start + size;
+            if contents_end > data.len() {
+                return Err(ArtichokeError::Io(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "truncated tar archive",
+                )));
+            }
+            let path = mount_point.join(name);
+            match typeflag {
+                b'5' => self.create_dir_all(path.as_path())?,
+                b'0' | 0 => {
+                    if let Some(parent) = path.parent() {
+                        self.create_dir_all(parent)?;
+                    }
+                    self.write_file(path.as_path(), &data[contents_start..contents_end])?;
+                }
+                _ => {}
+            }
+            offset = contents_end + (BLOCK - size % BLOCK) % BLOCK;
+        }
+        Ok(())
+    }
+
     pub fn create_dir_all<P: AsRef<Path>>(&self, path: P) -> Result<(), ArtichokeError> {
-        let cwd = self.fs.current_dir().map_err(ArtichokeError::Vfs)?;
+        let cwd = self.fs.current_dir().map_err(ArtichokeError::Io)?;
         let path = absolutize_relative_to(path.as_ref(), cwd.as_path())?;
         self.fs
             .create_dir_all(path.as_path())
-            .map_err(ArtichokeError::Vfs)
+            .map_err(ArtichokeError::Io)
     }
 
     pub fn is_file<P: AsRef<Path>>(&self, path: P) -> bool {
         self.fs
             .current_dir()
-            .map_err(ArtichokeError::Vfs)
+            .map_err(ArtichokeError::Io)
             .and_then(|cwd| absolutize_relative_to(path.as_ref(), cwd.as_path()))
             .map(|path| self.fs.is_file(path.as_path()))
             .unwrap_or_default()
     }
 
     pub fn read_file<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>, ArtichokeError> {
-        let cwd = self.fs.current_dir().map_err(ArtichokeError::Vfs)?;
+        let cwd = self.fs.current_dir().map_err(ArtichokeError::Io)?;
         let path = absolutize_relative_to(path.as_ref(), cwd.as_path())?;
         self.fs
             .read_file(path.as_path())
-            .map_err(ArtichokeError::Vfs)
+            .map_err(ArtichokeError::Io)
     }
 
     pub fn write_file<P, B>(&self, path: P, buf: B) -> Result<(), ArtichokeError>
@@ -62,11 +165,11 @@ impl Filesystem {
         P: AsRef<Path>,
         B: AsRef<[u8]>,
     {
-        let cwd = self.fs.current_dir().map_err(ArtichokeError::Vfs)?;
+        let cwd = self.fs.current_dir().map_err(ArtichokeError::Io)?;
         let path = absolutize_relative_to(path.as_ref(), cwd.as_path())?;
         self.fs
             .write_file(path.as_path(), buf.as_ref())
-            .map_err(ArtichokeError::Vfs)
+            .map_err(ArtichokeError::Io)
     }
 
     pub fn set_metadata<P: AsRef<Path>>(
@@ -74,11 +177,11 @@ impl Filesystem {
         path: P,
         metadata: Metadata,
     ) -> Result<(), ArtichokeError> {
-        let cwd = self.fs.current_dir().map_err(ArtichokeError::Vfs)?;
+        let cwd = self.fs.current_dir().map_err(ArtichokeError::Io)?;
         let path = absolutize_relative_to(path.as_ref(), cwd.as_path())?;
         self.fs
             .set_metadata(path.as_path(), metadata)
-            .map_err(ArtichokeError::Vfs)
+            .map_err(ArtichokeError::Io)
     }
 
     pub fn metadata<P: AsRef<Path>>(&self, path: P) -> Option<Metadata> {
@@ -120,16 +223,115 @@ impl Default for Metadata {
     }
 }
 
+/// Extract the entry path from a tar header block, honoring the `ustar`
+/// prefix field for long paths.
+fn tar_entry_name(header: &[u8]) -> Result<PathBuf, ArtichokeError> {
+    let invalid_name = || {
+        ArtichokeError::Io(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "tar entry names must be valid UTF-8",
+        ))
+    };
+    let name = &header[..100];
+    let name = name.split(|&byte| byte == 0).next().unwrap_or_default();
+    let name = str::from_utf8(name).map_err(|_| invalid_name())?;
+    let mut path = PathBuf::new();
+    if &header[257..262] == b"ustar" {
+        let prefix = &header[345..500];
+        let prefix = prefix.split(|&byte| byte == 0).next().unwrap_or_default();
+        let prefix = str::from_utf8(prefix).map_err(|_| invalid_name())?;
+        if !prefix.is_empty() {
+            path.push(prefix);
+        }
+    }
+    path.push(name.trim_start_matches("./"));
+    Ok(path)
+}
+
+/// Parse a NUL- or space-terminated octal field from a tar header.
+fn tar_octal_field(field: &[u8]) -> Result<usize, ArtichokeError> {
+    let invalid_field = || {
+        ArtichokeError::Io(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid octal field in tar header",
+        ))
+    };
+    let digits = str::from_utf8(field).map_err(|_| invalid_field())?;
+    let digits = digits.trim_matches(|c| c == ' ' || c == '\0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    usize::from_str_radix(digits, 8).map_err(|_| invalid_field())
+}
+
 fn absolutize_relative_to(path: &Path, cwd: &Path) -> Result<PathBuf, ArtichokeError> {
     if path.is_relative() {
         cwd.join(path)
             .parse_dot()
             .map_err(io::Error::from)
-            .map_err(ArtichokeError::Vfs)
+            .map_err(ArtichokeError::Io)
     } else {
         path.parse_dot()
             .map_err(io::Error::from)
-            .map_err(ArtichokeError::Vfs)
+            .map_err(ArtichokeError::Io)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+
+    use super::{ArchiveFormat, RUBY_LOAD_PATH};
+
+    /// Build a `ustar` entry — a 512-byte header followed by contents padded
+    /// to a block boundary.
+    fn tar_entry(name: &str, typeflag: u8, contents: &[u8]) -> Vec<u8> {
+        let mut header = vec![0; 512];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        let size = format!("{:011o}\0", contents.len());
+        header[124..136].copy_from_slice(size.as_bytes());
+        header[156] = typeflag;
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+        // The checksum is computed with the checksum field itself treated as
+        // spaces.
+        for byte in &mut header[148..156] {
+            *byte = b' ';
+        }
+        let checksum = header.iter().map(|&byte| u32::from(byte)).sum::<u32>();
+        let checksum = format!("{:06o}\0 ", checksum);
+        header[148..156].copy_from_slice(checksum.as_bytes());
+        let mut entry = header;
+        entry.extend_from_slice(contents);
+        let padding = (512 - contents.len() % 512) % 512;
+        entry.extend(std::iter::repeat(0).take(padding));
+        entry
+    }
+
+    #[test]
+    fn require_resolves_against_mounted_tar() {
+        let mut archive = Vec::new();
+        archive.extend(tar_entry("tarlib.rb", b'0', b"require 'tarlib/inner'\nTARLIB = 10"));
+        archive.extend(tar_entry("tarlib", b'5', b""));
+        archive.extend(tar_entry("tarlib/inner.rb", b'0', b"TARLIB_INNER = 11"));
+        archive.extend(vec![0; 1024]);
+        let archive: &'static [u8] = Box::leak(archive.into_boxed_slice());
+
+        let interp = crate::interpreter().expect("init");
+        interp
+            .0
+            .borrow()
+            .vfs
+            .mount_archive(archive, ArchiveFormat::Tar, RUBY_LOAD_PATH)
+            .expect("mount");
+        let result = interp.eval(b"require 'tarlib'").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"TARLIB + TARLIB_INNER").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 21);
+        // Requiring a mounted file is idempotent.
+        let result = interp.eval(b"require 'tarlib'").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
     }
 }
 
@@ -1,4 +1,9 @@
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::os::raw::c_int;
+
 use crate::sys;
+use crate::types::Ruby;
 use crate::value::Value;
 use crate::Artichoke;
 
@@ -36,6 +41,149 @@ impl Drop for ArenaIndex {
     }
 }
 
+/// Drop guard that re-enables the GC when it goes out of scope.
+///
+/// Returned by [`Artichoke::gc_disable`]. Like [`ArenaIndex`], letting the
+/// guard go out of scope is sufficient to restore the GC, including during a
+/// panic unwind. If the GC was already disabled when the guard was created,
+/// dropping the guard leaves it disabled.
+#[derive(Debug)]
+pub struct GcGuard {
+    was_enabled: bool,
+    interp: Artichoke,
+}
+
+impl Drop for GcGuard {
+    fn drop(&mut self) {
+        if self.was_enabled {
+            let mrb = self.interp.0.borrow().mrb;
+            unsafe { sys::mrb_sys_gc_enable(mrb) };
+        }
+    }
+}
+
+/// Control flow for the visitor passed to
+/// [`Artichoke::each_live_object`](Artichoke::each_live_object).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ObjectSpaceAction {
+    /// Continue walking the heap.
+    Continue,
+    /// Halt the heap walk. No further objects are yielded to the visitor.
+    Stop,
+}
+
+/// State threaded through [`sys::mrb_objspace_each_objects`] as a `void *` to
+/// reconstruct the visitor closure in [`each_object_callback`].
+struct EachObjectState<'a, F> {
+    interp: &'a Artichoke,
+    visitor: F,
+}
+
+unsafe extern "C" fn each_object_callback<F>(
+    _mrb: *mut sys::mrb_state,
+    obj: *mut sys::RBasic,
+    data: *mut c_void,
+) -> c_int
+where
+    F: FnMut(Value) -> ObjectSpaceAction,
+{
+    let state = &mut *(data as *mut EachObjectState<'_, F>);
+    let value = sys::mrb_sys_obj_value(obj as *mut c_void);
+    // The heap walker yields every slot in every heap page, including slots
+    // with internal-use type tags that do not correspond to Ruby objects.
+    // Skip them so the visitor only sees values it can safely inspect.
+    match value.tt {
+        sys::mrb_vtype::MRB_TT_FREE
+        | sys::mrb_vtype::MRB_TT_UNDEF
+        | sys::mrb_vtype::MRB_TT_ICLASS
+        | sys::mrb_vtype::MRB_TT_ENV
+        | sys::mrb_vtype::MRB_TT_FILE
+        | sys::mrb_vtype::MRB_TT_BREAK => return sys::MRB_EACH_OBJ_OK as c_int,
+        _ => {}
+    }
+    let value = Value::new(state.interp, value);
+    match (state.visitor)(value) {
+        ObjectSpaceAction::Continue => sys::MRB_EACH_OBJ_OK as c_int,
+        ObjectSpaceAction::Stop => sys::MRB_EACH_OBJ_BREAK as c_int,
+    }
+}
+
+impl Artichoke {
+    /// Walk the mruby heap and yield every live object to the visitor, like
+    /// MRI's `ObjectSpace.each_object`.
+    ///
+    /// The walk continues while the visitor returns
+    /// [`ObjectSpaceAction::Continue`] and halts when it returns
+    /// [`ObjectSpaceAction::Stop`]. [`sys::mrb_objspace_each_objects`]
+    /// performs a [full GC](MrbGarbageCollection::full_gc) before walking, so
+    /// only reachable objects are yielded.
+    ///
+    /// The visitor runs while the GC is iterating heap pages. It must not
+    /// allocate mruby objects or trigger a garbage collection; doing so may
+    /// invalidate the pages being walked.
+    pub fn each_live_object<F>(&self, f: F)
+    where
+        F: FnMut(Value) -> ObjectSpaceAction,
+    {
+        let mrb = self.0.borrow().mrb;
+        let mut state = EachObjectState {
+            interp: self,
+            visitor: f,
+        };
+        unsafe {
+            sys::mrb_objspace_each_objects(
+                mrb,
+                Some(each_object_callback::<F>),
+                &mut state as *mut EachObjectState<'_, F> as *mut c_void,
+            );
+        }
+    }
+
+    /// Count live objects on the heap grouped by [`Ruby`] type tag.
+    ///
+    /// Built on [`each_live_object`](Artichoke::each_live_object); useful for
+    /// memory profilers and leak detectors.
+    pub fn live_object_count_by_type(&self) -> HashMap<Ruby, usize> {
+        let mut counts = HashMap::new();
+        self.each_live_object(|value| {
+            *counts.entry(value.ruby_type()).or_insert(0) += 1;
+            ObjectSpaceAction::Continue
+        });
+        counts
+    }
+
+    /// Perform a [full GC](MrbGarbageCollection::full_gc).
+    pub fn gc_collect(&self) {
+        self.full_gc();
+    }
+
+    /// Perform an [incremental GC](MrbGarbageCollection::incremental_gc).
+    pub fn gc_incremental_collect(&self) {
+        self.incremental_gc();
+    }
+
+    /// Disable the GC for the lifetime of the returned [`GcGuard`].
+    ///
+    /// Unlike [`MrbGarbageCollection::disable_gc`], which requires a matching
+    /// call to [`MrbGarbageCollection::enable_gc`], the guard restores the
+    /// prior GC state on drop — even if the caller panics. Useful for
+    /// benchmarks and tests that need deterministic allocation patterns.
+    pub fn gc_disable(&self) -> GcGuard {
+        let mrb = self.0.borrow().mrb;
+        let was_enabled = unsafe { sys::mrb_sys_gc_disable(mrb) };
+        GcGuard {
+            was_enabled,
+            interp: self.clone(),
+        }
+    }
+
+    /// Check whether the GC is currently disabled.
+    pub fn gc_is_disabled(&self) -> bool {
+        let mrb = self.0.borrow().mrb;
+        !unsafe { sys::mrb_sys_gc_is_enabled(mrb) }
+    }
+}
+
 /// Garbage collection primitives for an mruby interpreter.
 pub trait MrbGarbageCollection {
     /// Create a savepoint in the GC arena which will allow mruby to deallocate
@@ -106,11 +254,13 @@ impl MrbGarbageCollection for Artichoke {
     fn incremental_gc(&self) {
         let mrb = self.0.borrow().mrb;
         unsafe { sys::mrb_incremental_gc(mrb) };
+        self.0.borrow_mut().record_gc_collection();
     }
 
     fn full_gc(&self) {
         let mrb = self.0.borrow().mrb;
         unsafe { sys::mrb_full_gc(mrb) };
+        self.0.borrow_mut().record_gc_collection();
     }
 
     fn enable_gc(&self) -> bool {
@@ -128,7 +278,8 @@ impl MrbGarbageCollection for Artichoke {
 mod tests {
     use artichoke_core::eval::Eval;
 
-    use crate::gc::MrbGarbageCollection;
+    use crate::gc::{MrbGarbageCollection, ObjectSpaceAction};
+    use crate::types::Ruby;
     use crate::value::ValueLike;
 
     #[test]
@@ -245,6 +396,59 @@ mod tests {
         assert_eq!(interp.live_object_count(), baseline_object_count);
     }
 
+    #[test]
+    fn gc_guard_restores_gc_on_drop() {
+        let interp = crate::interpreter().expect("init");
+        assert!(!interp.gc_is_disabled());
+        let live_before = {
+            let _guard = interp.gc_disable();
+            assert!(interp.gc_is_disabled());
+            let arena = interp.create_arena_savepoint();
+            for _ in 0..1000 {
+                let value = interp.eval(b"'gc guard'").expect("eval");
+                let _ = value.to_s();
+            }
+            arena.restore();
+            let live = interp.live_object_count();
+            interp.gc_collect();
+            assert_eq!(
+                interp.live_object_count(),
+                live,
+                "GC is disabled. No objects should be collected"
+            );
+            live
+        };
+        // Dropping the guard re-enables the GC.
+        assert!(!interp.gc_is_disabled());
+        interp.gc_collect();
+        assert!(
+            interp.live_object_count() < live_before,
+            "full GC after guard drop should free unreachable objects"
+        );
+    }
+
+    #[test]
+    fn each_live_object_visits_heap() {
+        let interp = crate::interpreter().expect("init");
+        interp
+            .eval(b"$strings = Array.new(10) { |i| i.to_s * 10 }")
+            .expect("eval");
+        interp.full_gc();
+        let counts = interp.live_object_count_by_type();
+        assert!(
+            counts.get(&Ruby::String).map_or(false, |&count| count >= 10),
+            "expected at least 10 live strings, got {:?}",
+            counts.get(&Ruby::String)
+        );
+        // A `Stop` visitor halts the walk after the first object.
+        let mut visited = 0;
+        interp.each_live_object(|_| {
+            visited += 1;
+            ObjectSpaceAction::Stop
+        });
+        assert_eq!(visited, 1);
+    }
+
     #[test]
     fn gc_functional_test() {
         let interp = crate::interpreter().expect("init");
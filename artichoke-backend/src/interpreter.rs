@@ -10,13 +10,67 @@ use crate::state::State;
 use crate::sys::{self, DescribeState};
 use crate::{Artichoke, ArtichokeError};
 
+/// Builder for Ruby sources to load into the
+/// [virtual filesystem](Filesystem) before interpreter init.
+///
+/// Embedders that bundle a library of `.rb` files into the binary with
+/// `include_bytes!` can stage them with `Preload` and construct the
+/// interpreter with [`interpreter_with_preload`]. Sources with relative paths
+/// are stored under [`crate::fs::RUBY_LOAD_PATH`] and are `require`-able by
+/// name before any user code runs.
+#[derive(Debug, Default, Clone)]
+pub struct Preload {
+    files: Vec<(&'static [u8], &'static [u8])>,
+}
+
+impl Preload {
+    /// Create a new, empty `Preload`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stage a `(path, content)` pair to load into the virtual filesystem.
+    pub fn source(mut self, path: &'static [u8], content: &'static [u8]) -> Self {
+        self.files.push((path, content));
+        self
+    }
+}
+
 /// Create and initialize an [`Artichoke`] interpreter.
 ///
 /// This function creates a new [`State`], embeds it in the [`sys::mrb_state`],
 /// initializes an [in memory virtual filesystem](Filesystem), and loads the
 /// [`extn`] extensions to Ruby Core and Stdlib.
 pub fn interpreter() -> Result<Artichoke, ArtichokeError> {
+    interpreter_init(Preload::new(), None)
+}
+
+/// Create and initialize an [`Artichoke`] interpreter with an
+/// embedder-supplied [name](crate::state::State::name).
+///
+/// Interpreters created with [`interpreter`] are assigned a default name of
+/// the form `artichoke#N`. Multi-tenant embedders should name each instance
+/// so interpreters can be told apart in logs and debug output.
+pub fn interpreter_named<T>(name: T) -> Result<Artichoke, ArtichokeError>
+where
+    T: Into<String>,
+{
+    interpreter_init(Preload::new(), Some(name.into()))
+}
+
+/// Create and initialize an [`Artichoke`] interpreter with a set of
+/// [`Preload`]ed Ruby sources.
+///
+/// The staged sources are written to the
+/// [virtual filesystem](Filesystem::preload) before the [`extn`] extensions
+/// are initialized, so they are atomically visible to all interpreter code.
+pub fn interpreter_with_preload(preload: Preload) -> Result<Artichoke, ArtichokeError> {
+    interpreter_init(preload, None)
+}
+
+fn interpreter_init(preload: Preload, name: Option<String>) -> Result<Artichoke, ArtichokeError> {
     let vfs = Filesystem::new()?;
+    vfs.preload(preload.files)?;
     let mrb = unsafe { sys::mrb_open() };
     if mrb.is_null() {
         error!("Failed to allocate mrb interprter");
@@ -24,7 +78,20 @@ pub fn interpreter() -> Result<Artichoke, ArtichokeError> {
     }
 
     let context = unsafe { sys::mrbc_context_new(mrb) };
-    let api = Rc::new(RefCell::new(State::new(mrb, context, vfs)));
+    unsafe {
+        // Capture parse errors on the context so `SyntaxError`s raised during
+        // the compile step of eval include the line number reported by the
+        // parser instead of a bare "syntax error" message. `Eval::eval` turns
+        // these messages into structured
+        // [`ArtichokeError::SyntaxError`](crate::ArtichokeError::SyntaxError)s.
+        (*context).set_capture_errors(1);
+    }
+    let state = if let Some(name) = name {
+        State::new_named(mrb, context, vfs, name)
+    } else {
+        State::new(mrb, context, vfs)
+    };
+    let api = Rc::new(RefCell::new(state));
 
     // Transmute the smart pointer that wraps the API and store it in the user
     // data of the mrb interpreter. After this operation, `Rc::strong_count`
@@ -72,9 +139,50 @@ pub fn interpreter() -> Result<Artichoke, ArtichokeError> {
 
 #[cfg(test)]
 mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::value::ValueLike;
+
     #[test]
     fn open_close() {
         let interp = super::interpreter().unwrap();
         drop(interp);
     }
+
+    #[test]
+    fn named_interpreters_are_distinguishable() {
+        let first = super::interpreter_named("tenant-a").unwrap();
+        let second = super::interpreter_named("tenant-b").unwrap();
+        assert_eq!(first.name(), "tenant-a");
+        assert_eq!(second.name(), "tenant-b");
+        let first_debug = format!("{:?}", first.0.borrow());
+        let second_debug = format!("{:?}", second.0.borrow());
+        assert_ne!(first_debug, second_debug);
+        assert!(first_debug.starts_with("tenant-a ("));
+        assert!(format!("{}", first.0.borrow()).starts_with("tenant-a ("));
+    }
+
+    #[test]
+    fn unnamed_interpreters_get_default_names() {
+        let first = super::interpreter().unwrap();
+        let second = super::interpreter().unwrap();
+        assert!(first.name().starts_with("artichoke#"));
+        assert!(second.name().starts_with("artichoke#"));
+        assert_ne!(first.name(), second.name());
+    }
+
+    #[test]
+    fn preloaded_sources_are_requirable() {
+        let preload = super::Preload::new()
+            .source(b"lib1.rb", b"require 'lib2'\n\ndef lib1; lib2 + 1; end")
+            .source(b"lib2.rb", b"def lib2; 2; end");
+        let interp = super::interpreter_with_preload(preload).unwrap();
+        let result = interp.eval(b"require 'lib1'").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"require 'lib2'").expect("eval");
+        // `lib2` was already loaded by `lib1`.
+        assert!(!result.try_into::<bool>().expect("convert"));
+        let result = interp.eval(b"lib1").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 3);
+    }
 }
@@ -84,8 +84,10 @@ extern crate downcast;
 #[macro_use]
 extern crate log;
 
+use std::borrow::Cow;
 use std::cell::RefCell;
-use std::rc::Rc;
+use std::ffi::CString;
+use std::rc::{Rc, Weak};
 
 #[macro_use]
 #[doc(hidden)]
@@ -104,6 +106,7 @@ mod interpreter;
 pub mod load;
 pub mod method;
 pub mod module;
+pub mod object_id;
 pub mod state;
 /// C bindings for mruby, customized for Artichoke.
 pub mod sys;
@@ -113,7 +116,7 @@ pub mod value;
 pub mod warn;
 
 pub use artichoke_core::ArtichokeError;
-pub use interpreter::interpreter;
+pub use interpreter::{interpreter, interpreter_named, interpreter_with_preload, Preload};
 
 /// Interpreter instance.
 ///
@@ -132,10 +135,622 @@ pub use interpreter::interpreter;
 #[derive(Debug, Clone)]
 pub struct Artichoke(pub Rc<RefCell<state::State>>); // TODO: this should not be pub
 
+/// VM code fetch hook that enforces the instruction limit set by
+/// [`Artichoke::set_instruction_limit`].
+///
+/// This hook runs before every VM instruction, decrements the remaining
+/// instruction budget on the [`State`](state::State), and raises
+/// `InstructionLimitExceeded` when the budget is exhausted.
+unsafe extern "C" fn instruction_limit_hook(
+    mrb: *mut sys::mrb_state,
+    _irep: *mut sys::mrb_irep,
+    _pc: *const sys::mrb_code,
+    _regs: *mut sys::mrb_value,
+) {
+    let interp = unwrap_interpreter!(mrb, or_else = ());
+    let exhausted = interp.0.borrow_mut().consume_instruction();
+    if exhausted {
+        // `mrb_sys_raise` will unwind the stack with `longjmp`. Drop the
+        // interpreter first so the strong count taken by `unwrap_interpreter!`
+        // is released.
+        drop(interp);
+        sys::mrb_sys_raise(
+            mrb,
+            b"InstructionLimitExceeded\0".as_ptr() as *const i8,
+            b"instruction limit exceeded\0".as_ptr() as *const i8,
+        );
+    }
+}
+
+/// Non-owning reference to an [`Artichoke`] interpreter.
+///
+/// `MRB_TT_DATA` objects live on the mruby heap, which is owned by the
+/// [`State`](state::State). If such an object holds a strong [`Artichoke`]
+/// clone — for example so its destructor can remove an entry from a registry
+/// — the `Rc` strong count can never reach zero and the `State` leaks.
+/// Extension types in that position should store a `WeakArtichoke` created
+/// with [`Artichoke::downgrade`] and upgrade it on demand.
+#[derive(Debug, Clone)]
+pub struct WeakArtichoke(Weak<RefCell<state::State>>);
+
+impl WeakArtichoke {
+    /// Attempt to upgrade to a strong [`Artichoke`] reference.
+    ///
+    /// Returns [`None`] if the interpreter has been [closed](Artichoke::close)
+    /// and the underlying [`State`](state::State) dropped, which is the
+    /// expected outcome for destructors that run during `mrb_close`.
+    pub fn try_upgrade(&self) -> Option<Artichoke> {
+        self.0.upgrade().map(Artichoke)
+    }
+}
+
 impl Artichoke {
+    /// Create a [`WeakArtichoke`] that refers to this interpreter without
+    /// keeping the [`State`](state::State) alive.
+    pub fn downgrade(&self) -> WeakArtichoke {
+        WeakArtichoke(Rc::downgrade(&self.0))
+    }
+
+    /// Define a global function callable from any Ruby scope.
+    ///
+    /// Global functions are defined as methods on the
+    /// [`Kernel`](extn::core::kernel) module, which makes them resolvable with
+    /// a bare call like `puts "foo"` from any context, mirroring a top-level
+    /// `def` in Ruby source.
+    pub fn define_global_function(
+        &self,
+        name: &str,
+        func: def::Method,
+        args: sys::mrb_aspec,
+    ) -> Result<(), ArtichokeError> {
+        let mrb = self.0.borrow().mrb;
+        let spec = method::Spec::new(method::Type::Instance, name, func, args);
+        unsafe { spec.define(self, (*mrb).kernel_module) }
+    }
+
+    /// Check whether a [`Value`](value::Value) responds to a method.
+    ///
+    /// Calls `respond_to?` on the value, which is useful inside Rust-backed
+    /// methods that receive [`sys::mrb_value`] arguments and must dispatch
+    /// based on duck type.
+    pub fn respond_to(
+        &self,
+        value: &value::Value,
+        method: &str,
+    ) -> Result<bool, ArtichokeError> {
+        value::ValueLike::respond_to(value, method)
+    }
+
+    /// Check whether a [`Value`](value::Value) is an instance of the class or
+    /// module named `class_name` or one of its subclasses.
+    ///
+    /// Returns [`ArtichokeError::NotDefined`] if no class or module with the
+    /// given name is defined in the interpreter.
+    pub fn is_a(&self, value: &value::Value, class_name: &str) -> Result<bool, ArtichokeError> {
+        let mrb = self.0.borrow().mrb;
+        let class_cstr = CString::new(class_name)
+            .map_err(|_| ArtichokeError::NotDefined(Cow::Owned(String::from(class_name))))?;
+        let is_a = unsafe {
+            if sys::mrb_class_defined(mrb, class_cstr.as_ptr()) == 0 {
+                return Err(ArtichokeError::NotDefined(Cow::Owned(String::from(
+                    class_name,
+                ))));
+            }
+            let rclass = sys::mrb_class_get(mrb, class_cstr.as_ptr());
+            sys::mrb_obj_is_kind_of(mrb, value.inner(), rclass)
+        };
+        Ok(is_a != 0)
+    }
+
+    /// Validate a Ruby instance variable name.
+    ///
+    /// Names must start with `@` followed by an identifier.
+    fn validate_ivar_name(name: &str) -> Result<(), ArtichokeError> {
+        if !name.starts_with('@') {
+            return Err(ArtichokeError::InvalidIvarName(String::from(name)));
+        }
+        let mut chars = name[1..].chars();
+        let valid = match chars.next() {
+            Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            }
+            _ => false,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(ArtichokeError::InvalidIvarName(String::from(name)))
+        }
+    }
+
+    /// Read an instance variable on a [`Value`](value::Value).
+    ///
+    /// Returns `nil` if the instance variable is not set, mirroring Ruby
+    /// semantics. Returns [`ArtichokeError::InvalidIvarName`] if `name` is not
+    /// an `@`-prefixed identifier.
+    pub fn ivar_get(
+        &self,
+        obj: &value::Value,
+        name: &str,
+    ) -> Result<value::Value, ArtichokeError> {
+        Self::validate_ivar_name(name)?;
+        let mrb = self.0.borrow().mrb;
+        let sym = self.0.borrow_mut().sym_intern(name.as_bytes().to_vec());
+        let result = unsafe { sys::mrb_iv_get(mrb, obj.inner(), sym) };
+        Ok(value::Value::new(self, result))
+    }
+
+    /// Write an instance variable on a [`Value`](value::Value).
+    ///
+    /// Rust-backed Ruby objects can use instance variables to store state on
+    /// the mruby heap, where it is visible to the GC, instead of in
+    /// `MRB_TT_DATA` pointers.
+    ///
+    /// Returns [`ArtichokeError::InvalidIvarName`] if `name` is not an
+    /// `@`-prefixed identifier and [`ArtichokeError::Exec`] if the receiver
+    /// cannot carry instance variables, for example `Fixnum`s and `Symbol`s.
+    pub fn ivar_set(
+        &self,
+        obj: &value::Value,
+        name: &str,
+        value: value::Value,
+    ) -> Result<(), ArtichokeError> {
+        Self::validate_ivar_name(name)?;
+        // `sys::mrb_iv_set` raises `ArgumentError` on receivers that do not
+        // carry instance variable tables, which would unwind over this Rust
+        // frame with `longjmp`. Reject those receivers before calling into
+        // the VM.
+        match obj.ruby_type() {
+            types::Ruby::Object
+            | types::Ruby::Class
+            | types::Ruby::Module
+            | types::Ruby::SingletonClass
+            | types::Ruby::Hash
+            | types::Ruby::Data
+            | types::Ruby::Exception => {}
+            _ => {
+                return Err(ArtichokeError::Exec(format!(
+                    "cannot set instance variable on {}",
+                    obj.pretty_name()
+                )))
+            }
+        }
+        let mrb = self.0.borrow().mrb;
+        let sym = self.0.borrow_mut().sym_intern(name.as_bytes().to_vec());
+        unsafe {
+            sys::mrb_iv_set(mrb, obj.inner(), sym, value.inner());
+        }
+        Ok(())
+    }
+
+    /// Check whether an instance variable is set on a
+    /// [`Value`](value::Value).
+    ///
+    /// Returns [`ArtichokeError::InvalidIvarName`] if `name` is not an
+    /// `@`-prefixed identifier.
+    pub fn ivar_defined(&self, obj: &value::Value, name: &str) -> Result<bool, ArtichokeError> {
+        Self::validate_ivar_name(name)?;
+        let mrb = self.0.borrow().mrb;
+        let sym = self.0.borrow_mut().sym_intern(name.as_bytes().to_vec());
+        let defined = unsafe { sys::mrb_iv_defined(mrb, obj.inner(), sym) };
+        Ok(defined != 0)
+    }
+
+    /// Invoke a Ruby method on a [`Value`](value::Value) receiver.
+    ///
+    /// The call is protected by an `mrb_protect` boundary, so Ruby exceptions
+    /// are returned as [`ArtichokeError::Exec`] instead of unwinding into
+    /// Rust frames. This avoids both serializing the receiver into an eval
+    /// string and calling [`sys::mrb_funcall`] directly.
+    pub fn call_method(
+        &self,
+        receiver: &value::Value,
+        method: &str,
+        args: &[value::Value],
+    ) -> Result<value::Value, ArtichokeError> {
+        value::ValueLike::funcall::<value::Value>(receiver, method, args, None)
+    }
+
+    /// Invoke a Ruby method on a [`Value`](value::Value) receiver with a
+    /// block.
+    ///
+    /// `block` must be a `Proc` or an object that implements `to_proc`, for
+    /// example a `Symbol`. See [`Artichoke::call_method`].
+    pub fn call_method_with_block(
+        &self,
+        receiver: &value::Value,
+        method: &str,
+        args: &[value::Value],
+        block: value::Value,
+    ) -> Result<value::Value, ArtichokeError> {
+        value::ValueLike::funcall::<value::Value>(receiver, method, args, Some(block))
+    }
+
+    /// Call a Ruby `Proc` or lambda with the given arguments.
+    ///
+    /// Wraps [`sys::mrb_yield_argv`], so calling semantics match block yield:
+    /// lambdas enforce their arity and procs splat single `Array` arguments.
+    /// Ruby exceptions raised by the proc body are returned as
+    /// [`ArtichokeError::Exec`].
+    ///
+    /// Returns [`ArtichokeError::ConvertToRust`] if the receiver is not a
+    /// `Proc`.
+    pub fn call_proc(
+        &self,
+        proc: &value::Value,
+        args: &[value::Value],
+    ) -> Result<value::Value, ArtichokeError> {
+        use crate::exception::{ExceptionHandler, LastError};
+        use crate::gc::MrbGarbageCollection;
+        use std::convert::TryFrom;
+
+        if proc.ruby_type() != types::Ruby::Proc {
+            return Err(ArtichokeError::ConvertToRust {
+                from: proc.ruby_type(),
+                to: types::Rust::Object,
+            });
+        }
+        if args.len() > value::MRB_FUNCALL_ARGC_MAX {
+            return Err(ArtichokeError::TooManyArgs {
+                given: args.len(),
+                max: value::MRB_FUNCALL_ARGC_MAX,
+            });
+        }
+        let mrb = self.0.borrow().mrb;
+        let _arena = self.create_arena_savepoint();
+        let args = args.iter().map(value::Value::inner).collect::<Vec<_>>();
+        let argc = types::Int::try_from(args.len()).unwrap_or_default();
+        let result = unsafe { sys::mrb_yield_argv(mrb, proc.inner(), argc, args.as_ptr()) };
+        let result = value::Value::new(self, result);
+        match self.last_error() {
+            LastError::Some(exception) => {
+                warn!("runtime error with exception backtrace: {}", exception);
+                Err(ArtichokeError::Exec(exception.to_string()))
+            }
+            LastError::UnableToExtract(err) => {
+                error!("failed to extract exception after runtime error: {}", err);
+                Err(err)
+            }
+            LastError::None if result.is_unreachable() => Err(ArtichokeError::UnreachableValue),
+            LastError::None => Ok(result),
+        }
+    }
+
+    /// The name assigned to this interpreter instance.
+    ///
+    /// Pass-through to [`State::name`](state::State::name). Names default to
+    /// `artichoke#N` and can be set with [`interpreter_named`].
+    pub fn name(&self) -> String {
+        self.0.borrow().name().to_owned()
+    }
+
+    /// The object ID of a [`Value`](value::Value).
+    ///
+    /// Wraps [`sys::mrb_obj_id`]. Object IDs are stable for the lifetime of
+    /// an object, but IDs of heap-allocated objects are derived from object
+    /// pointers and may be recycled by the GC once the object is collected.
+    /// See [`ObjectIdMap`](object_id::ObjectIdMap) for identity-keyed
+    /// storage.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn object_id(&self, value: &value::Value) -> usize {
+        let id = unsafe { sys::mrb_obj_id(value.inner()) };
+        // IDs of negative `Fixnum`s are negative; the sign-preserving cast
+        // keeps distinct IDs distinct.
+        id as usize
+    }
+
+    /// Render the `inspect` representation of a [`Value`](value::Value).
+    ///
+    /// Calls [`sys::mrb_inspect`] on the value directly, which avoids binding
+    /// the value to a variable and round-tripping through
+    /// [`Eval::eval`](eval::Eval). Useful for logging and test assertions.
+    pub fn inspect_value(&self, value: &value::Value) -> Result<String, ArtichokeError> {
+        let mrb = self.0.borrow().mrb;
+        let inspect = unsafe { sys::mrb_inspect(mrb, value.inner()) };
+        value::ValueLike::try_into::<String>(value::Value::new(self, inspect))
+    }
+
+    /// Render the `to_s` representation of a [`Value`](value::Value).
+    ///
+    /// Calls [`sys::mrb_obj_as_string`] on the value directly. See
+    /// [`Artichoke::inspect_value`].
+    pub fn to_s(&self, value: &value::Value) -> Result<String, ArtichokeError> {
+        let mrb = self.0.borrow().mrb;
+        let string = unsafe { sys::mrb_obj_as_string(mrb, value.inner()) };
+        value::ValueLike::try_into::<String>(value::Value::new(self, string))
+    }
+
+    /// Freeze a [`Value`](value::Value).
+    ///
+    /// Calls [`sys::mrb_obj_freeze`] on the value directly. Frozen objects
+    /// raise `FrozenError` when mutated; check frozen state with
+    /// [`ValueLike::is_frozen`](artichoke_core::value::Value::is_frozen).
+    pub fn freeze_value(&self, value: &value::Value) -> Result<(), ArtichokeError> {
+        let mrb = self.0.borrow().mrb;
+        unsafe {
+            sys::mrb_obj_freeze(mrb, value.inner());
+        }
+        Ok(())
+    }
+
+    /// Extend an object with a module, like Ruby's `Object#extend`.
+    ///
+    /// The module's instance methods become singleton methods on `obj` only.
+    /// To extend a class so module methods become class methods, use
+    /// [`class::Builder::extend_module`].
+    pub fn extend_object(
+        &self,
+        obj: &value::Value,
+        module: &module::Spec,
+    ) -> Result<(), ArtichokeError> {
+        let rclass = module.rclass(self).ok_or_else(|| {
+            ArtichokeError::NotDefined(Cow::Owned(module.fqname().into_owned()))
+        })?;
+        let module = value::Value::new(self, unsafe { sys::mrb_sys_module_value(rclass) });
+        value::ValueLike::funcall::<value::Value>(obj, "extend", &[module], None)?;
+        Ok(())
+    }
+
+    /// Limit the number of VM instructions the interpreter may execute per
+    /// eval.
+    ///
+    /// Sandboxed embedders can use an instruction limit to interrupt Ruby code
+    /// that would otherwise run forever, e.g. `while true; end`. When the
+    /// budget is exhausted, the VM raises an `InstructionLimitExceeded`
+    /// exception, which is a subclass of `ScriptError`.
+    ///
+    /// The budget is reset to the limit after each [`Eval::eval`](eval::Eval)
+    /// so repeated evals each get a fresh budget.
+    pub fn set_instruction_limit(&self, limit: usize) {
+        let mrb = self.0.borrow().mrb;
+        self.0.borrow_mut().set_instruction_limit(Some(limit));
+        unsafe {
+            (*mrb).code_fetch_hook = Some(instruction_limit_hook);
+        }
+    }
+
+    /// Remove the [instruction limit](Artichoke::set_instruction_limit), if
+    /// one is set.
+    pub fn clear_instruction_limit(&self) {
+        let mrb = self.0.borrow().mrb;
+        self.0.borrow_mut().set_instruction_limit(None);
+        unsafe {
+            (*mrb).code_fetch_hook = None;
+        }
+    }
+
+    /// Eval Ruby code and serialize the result to a [`serde_json::Value`].
+    ///
+    /// Ruby `nil`, `true`, `false`, `Integer`, `Float`, `String`, `Symbol`,
+    /// `Array`, and `Hash` results serialize to their JSON equivalents; see
+    /// the `convert::serde` module. Results with no JSON representation are
+    /// reported as [`ArtichokeError::Io`] with kind
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData).
+    #[cfg(feature = "artichoke-serde")]
+    pub fn eval_and_serialize(&self, code: &[u8]) -> Result<serde_json::Value, ArtichokeError> {
+        use artichoke_core::eval::Eval;
+
+        let value = self.eval(code)?;
+        serde_json::to_value(&value).map_err(|err| {
+            ArtichokeError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                err.to_string(),
+            ))
+        })
+    }
+
     /// Consume an interpreter and free all
     /// [live](gc::MrbGarbageCollection::live_objects) [`Value`](value::Value)s.
     pub fn close(self) {
         self.0.borrow_mut().close();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::value::Value as _;
+    use std::rc::Rc;
+
+    use crate::convert::Convert;
+    use crate::sys;
+    use crate::value::Value;
+    use crate::ArtichokeError;
+
+    unsafe extern "C" fn greet(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        let name = mrb_get_args!(mrb, required = 1);
+        let interp = unwrap_interpreter!(mrb);
+        let name = Value::new(&interp, name);
+        let greeting: Value = interp.convert(format!("Hello, {}!", name.to_s()));
+        greeting.inner()
+    }
+
+    #[test]
+    fn inspect_value_and_to_s() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"'artichoke'").expect("eval");
+        assert_eq!(
+            interp.inspect_value(&value).expect("inspect"),
+            r#""artichoke""#
+        );
+        assert_eq!(interp.to_s(&value).expect("to_s"), "artichoke");
+        let value = interp.eval(b"[1, :two, nil]").expect("eval");
+        assert_eq!(
+            interp.inspect_value(&value).expect("inspect"),
+            "[1, :two, nil]"
+        );
+        let value = interp.eval(b"nil").expect("eval");
+        assert_eq!(interp.inspect_value(&value).expect("inspect"), "nil");
+        assert_eq!(interp.to_s(&value).expect("to_s"), "");
+    }
+
+    #[test]
+    fn freeze_value_raises_frozen_error_on_mutation() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"$frozen = 'artichoke'").expect("eval");
+        assert!(!artichoke_core::value::Value::is_frozen(&value));
+        interp.freeze_value(&value).expect("freeze");
+        assert!(artichoke_core::value::Value::is_frozen(&value));
+        let err = interp.eval(b"$frozen << 'x'").map(|_| ()).unwrap_err();
+        assert!(
+            format!("{}", err).contains("FrozenError"),
+            "expected FrozenError, got {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn downgrade_upgrade_roundtrip() {
+        let interp = crate::interpreter().expect("init");
+        let weak = interp.downgrade();
+        // Downgrading must not affect the strong count.
+        assert_eq!(Rc::strong_count(&interp.0), 1);
+        let upgraded = weak.try_upgrade().expect("upgrade");
+        assert_eq!(Rc::strong_count(&interp.0), 2);
+        drop(upgraded);
+        assert_eq!(Rc::strong_count(&interp.0), 1);
+    }
+
+    #[test]
+    fn weak_does_not_survive_close() {
+        let interp = crate::interpreter().expect("init");
+        let weak = interp.downgrade();
+        interp.close();
+        assert!(weak.try_upgrade().is_none());
+    }
+
+    #[test]
+    fn closed_interpreter_is_a_typed_error() {
+        let interp = crate::interpreter().expect("init");
+        let clone = interp.clone();
+        interp.close();
+        // Clones that outlive the close observe a typed error instead of
+        // dereferencing the freed `mrb_state`.
+        assert_eq!(
+            clone.eval(b"1 + 1").map(|_| ()),
+            Err(ArtichokeError::InterpreterClosed)
+        );
+        assert_eq!(
+            clone.check_syntax(b"1 + 1"),
+            Err(ArtichokeError::InterpreterClosed)
+        );
+    }
+
+    #[test]
+    fn respond_to() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"255").expect("eval");
+        assert_eq!(interp.respond_to(&value, "to_s"), Ok(true));
+        assert_eq!(interp.respond_to(&value, "push"), Ok(false));
+    }
+
+    #[test]
+    fn is_a() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"255").expect("eval");
+        assert_eq!(interp.is_a(&value, "Integer"), Ok(true));
+        assert_eq!(interp.is_a(&value, "Numeric"), Ok(true));
+        assert_eq!(interp.is_a(&value, "String"), Ok(false));
+        assert!(interp.is_a(&value, "NotAClass").is_err());
+    }
+
+    #[test]
+    fn ivar_get_set_defined() {
+        let interp = crate::interpreter().expect("init");
+        let obj = interp.eval(b"Object.new").expect("eval");
+        assert_eq!(interp.ivar_defined(&obj, "@foo"), Ok(false));
+        let value = interp.convert(7);
+        interp.ivar_set(&obj, "@foo", value).expect("ivar_set");
+        assert_eq!(interp.ivar_defined(&obj, "@foo"), Ok(true));
+        let result = interp.ivar_get(&obj, "@foo").expect("ivar_get");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 7);
+        // Unset ivars read as `nil`.
+        let result = interp.ivar_get(&obj, "@bar").expect("ivar_get");
+        assert_eq!(result.try_into::<Option<i64>>().expect("convert"), None);
+    }
+
+    #[test]
+    fn ivar_name_validation() {
+        let interp = crate::interpreter().expect("init");
+        let obj = interp.eval(b"Object.new").expect("eval");
+        for name in &["foo", "@", "@@foo", "@1foo", "@foo bar"] {
+            let err = interp.ivar_get(&obj, name).unwrap_err();
+            match err {
+                crate::ArtichokeError::InvalidIvarName(invalid) => {
+                    assert_eq!(&invalid, name)
+                }
+                err => panic!("expected ArtichokeError::InvalidIvarName, got {:?}", err),
+            }
+        }
+        // Receivers without instance variable tables are rejected before
+        // calling into the VM.
+        let fixnum = interp.convert(255);
+        let value = interp.convert(7);
+        assert!(interp.ivar_set(&fixnum, "@foo", value).is_err());
+    }
+
+    #[test]
+    fn call_method() {
+        let interp = crate::interpreter().expect("init");
+        let ary = interp.eval(b"[1, 2, 3]").expect("eval");
+        let result = interp.call_method(&ary, "sum", &[]).expect("funcall");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 6);
+        let err = interp.call_method(&ary, "not_a_method", &[]).unwrap_err();
+        match err {
+            crate::ArtichokeError::Exec(message) => {
+                assert!(message.starts_with("NoMethodError"))
+            }
+            err => panic!("expected ArtichokeError::Exec, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn call_method_with_block() {
+        let interp = crate::interpreter().expect("init");
+        let ary = interp.eval(b"[1, 2, 3]").expect("eval");
+        let block = interp.eval(b"proc { |x| x * 2 }").expect("eval");
+        let result = interp
+            .call_method_with_block(&ary, "map", &[], block)
+            .expect("funcall");
+        assert_eq!(
+            result.try_into::<Vec<i64>>().expect("convert"),
+            vec![2, 4, 6]
+        );
+    }
+
+    #[test]
+    fn instruction_limit_interrupts_infinite_loop() {
+        let interp = crate::interpreter().expect("init");
+        interp.set_instruction_limit(1000);
+        let err = interp.eval(b"while true; end").map(|_| ()).unwrap_err();
+        assert!(
+            format!("{}", err).contains("InstructionLimitExceeded"),
+            "expected InstructionLimitExceeded, got {:?}",
+            err
+        );
+        // Each eval gets a fresh instruction budget.
+        let result = interp.eval(b"1 + 1").map(|_| ());
+        assert_eq!(result, Ok(()));
+        interp.clear_instruction_limit();
+        let result = interp.eval(b"'a' * 10").map(|_| ());
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn define_global_function() {
+        let interp = crate::interpreter().expect("init");
+        interp
+            .define_global_function("greet", greet, sys::mrb_args_req(1))
+            .expect("def");
+        let result = interp.eval(b"greet('world')").expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            "Hello, world!"
+        );
+        let result = interp.eval(b"self.greet('artichoke')").expect("eval");
+        assert_eq!(
+            result.try_into::<String>().expect("convert"),
+            "Hello, artichoke!"
+        );
+    }
+}
@@ -16,7 +16,7 @@ impl LoadSources for Artichoke {
     {
         let api = self.0.borrow();
         let path = fs::bytes_to_osstr(self, filename).map_err(|err| {
-            ArtichokeError::Vfs(io::Error::new(io::ErrorKind::Other, err.to_string()))
+            ArtichokeError::Io(io::Error::new(io::ErrorKind::Other, err.to_string()))
         })?;
         let path = Path::new(path);
         let path = if path.is_relative() {
@@ -47,7 +47,7 @@ impl LoadSources for Artichoke {
     {
         let api = self.0.borrow();
         let path = fs::bytes_to_osstr(self, filename).map_err(|err| {
-            ArtichokeError::Vfs(io::Error::new(io::ErrorKind::Other, err.to_string()))
+            ArtichokeError::Io(io::Error::new(io::ErrorKind::Other, err.to_string()))
         })?;
         let path = Path::new(path);
         let path = if path.is_relative() {
@@ -63,3 +63,42 @@ impl LoadSources for Artichoke {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::file::File;
+    use artichoke_core::value::Value as _;
+
+    use crate::{Artichoke, ArtichokeError};
+
+    struct Counted;
+
+    impl File for Counted {
+        type Artichoke = Artichoke;
+
+        fn require(interp: &Self::Artichoke) -> Result<(), ArtichokeError> {
+            interp.eval(b"$counted_requires = ($counted_requires || 0) + 1")?;
+            Ok(())
+        }
+
+        fn is_loaded(interp: &Self::Artichoke) -> bool {
+            interp
+                .eval(b"!$counted_requires.nil?")
+                .and_then(|loaded| loaded.try_into::<bool>())
+                .unwrap_or_default()
+        }
+    }
+
+    #[test]
+    fn require_once_is_idempotent() {
+        let interp = crate::interpreter().expect("init");
+        assert!(!Counted::is_loaded(&interp));
+        let loaded = Counted::require_once(&interp).expect("require");
+        assert!(loaded, "first require_once loads the file");
+        let loaded = Counted::require_once(&interp).expect("require");
+        assert!(!loaded, "second require_once is a no-op");
+        let result = interp.eval(b"$counted_requires").expect("eval");
+        assert_eq!(result.try_into::<i64>().expect("convert"), 1);
+    }
+}
@@ -39,7 +39,10 @@ pub mod argspec {
     pub const REQ1_OPT2: &[u8] = b"o|oo\0";
     pub const REQ1_REQBLOCK: &[u8] = b"o&\0";
     pub const REQ1_REQBLOCK_OPT1: &[u8] = b"o&|o?\0";
+    pub const REQBLOCK: &[u8] = b"&\0";
     pub const REQ2: &[u8] = b"oo\0";
+    pub const REQ3: &[u8] = b"ooo\0";
+    pub const OPT1_OPTBLOCK: &[u8] = b"&|o?\0";
     pub const OPT2_OPTBLOCK: &[u8] = b"&|o?o?\0";
     pub const REQ2_OPT1: &[u8] = b"oo|o\0";
     pub const REST: &[u8] = b"*\0";
@@ -138,6 +141,16 @@ macro_rules! mrb_get_args {
             _ => unreachable!("mrb_get_args should have raised"),
         }
     }};
+    ($mrb:expr, &block) => {{
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::REQBLOCK.as_ptr() as *const i8,
+            block.as_mut_ptr(),
+        );
+        let block = block.assume_init();
+        $crate::value::Block::new(block)
+    }};
     ($mrb:expr, required = 1, &block) => {{
         let mut req1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
         let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
@@ -213,6 +226,47 @@ macro_rules! mrb_get_args {
             _ => unreachable!("mrb_get_args should have raised"),
         }
     }};
+    ($mrb:expr, required = 3) => {{
+        let mut req1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let mut req2 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let mut req3 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let argc = $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::REQ3.as_ptr() as *const i8,
+            req1.as_mut_ptr(),
+            req2.as_mut_ptr(),
+            req3.as_mut_ptr(),
+        );
+        match argc {
+            3 => {
+                let req1 = req1.assume_init();
+                let req2 = req2.assume_init();
+                let req3 = req3.assume_init();
+                (req1, req2, req3)
+            }
+            _ => unreachable!("mrb_get_args should have raised"),
+        }
+    }};
+    ($mrb:expr, optional = 1, &block) => {{
+        let mut opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let mut has_opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_bool>::uninit();
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::OPT1_OPTBLOCK.as_ptr() as *const i8,
+            block.as_mut_ptr(),
+            opt1.as_mut_ptr(),
+            has_opt1.as_mut_ptr(),
+        );
+        let has_opt1 = has_opt1.assume_init() != 0;
+        let opt1 = if has_opt1 {
+            Some(opt1.assume_init())
+        } else {
+            None
+        };
+        let block = block.assume_init();
+        (opt1, $crate::value::Block::new(block))
+    }};
     ($mrb:expr, optional = 2, &block) => {{
         let mut opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
         let mut has_opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_bool>::uninit();
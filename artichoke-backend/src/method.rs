@@ -39,6 +39,10 @@ impl Spec {
         }
     }
 
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
     pub fn method_type(&self) -> &Type {
         &self.method_type
     }
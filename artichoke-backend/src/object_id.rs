@@ -0,0 +1,106 @@
+//! Identity-based storage keyed by Ruby object ID.
+
+use std::collections::HashMap;
+
+use crate::value::Value;
+use crate::Artichoke;
+
+/// Map from Ruby object identity to Rust data.
+///
+/// `ObjectIdMap` is a newtype around `HashMap<usize, V>` keyed by
+/// [`Artichoke::object_id`]. Extension code can use it to associate Rust data
+/// with individual Ruby objects, for example event listeners keyed by a Ruby
+/// callback object.
+///
+/// # GC caveat
+///
+/// Object IDs of heap-allocated objects are derived from object pointers and
+/// may be recycled by the garbage collector: a new object allocated after an
+/// old one is collected can reuse the old object's ID. Callers must ensure
+/// objects with entries in the map stay rooted for the lifetime of the map,
+/// for example by holding them in a Ruby global or instance variable.
+#[derive(Debug, Default)]
+pub struct ObjectIdMap<V>(HashMap<usize, V>);
+
+impl<V> ObjectIdMap<V> {
+    /// Construct a new, empty `ObjectIdMap`.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Insert data keyed by a Ruby object's identity.
+    ///
+    /// Returns the previously stored data if the object already had an entry.
+    pub fn insert_for_object(&mut self, interp: &Artichoke, obj: &Value, value: V) -> Option<V> {
+        self.0.insert(interp.object_id(obj), value)
+    }
+
+    /// Retrieve the data stored for a Ruby object's identity.
+    pub fn get_for_object(&self, interp: &Artichoke, obj: &Value) -> Option<&V> {
+        self.0.get(&interp.object_id(obj))
+    }
+
+    /// Remove and return the data stored for a Ruby object's identity.
+    pub fn remove_for_object(&mut self, interp: &Artichoke, obj: &Value) -> Option<V> {
+        self.0.remove(&interp.object_id(obj))
+    }
+
+    /// Number of objects with entries in the map.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+
+    use crate::object_id::ObjectIdMap;
+
+    #[test]
+    fn distinct_objects_have_distinct_ids() {
+        let interp = crate::interpreter().expect("init");
+        // Assign to globals so the GC cannot collect and recycle either
+        // object mid-test.
+        let first = interp.eval(b"$first = 'a'").expect("eval");
+        let second = interp.eval(b"$second = 'a'").expect("eval");
+        assert_ne!(interp.object_id(&first), interp.object_id(&second));
+    }
+
+    #[test]
+    fn object_id_is_stable_for_the_same_object() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"$obj = 'artichoke'").expect("eval");
+        let id = interp.object_id(&value);
+        let same = interp.eval(b"$obj").expect("eval");
+        assert_eq!(interp.object_id(&same), id);
+        assert_eq!(interp.object_id(&value), id);
+    }
+
+    #[test]
+    fn map_roundtrip() {
+        let interp = crate::interpreter().expect("init");
+        let mut map = ObjectIdMap::new();
+        let listener = interp.eval(b"$listener = Object.new").expect("eval");
+        let other = interp.eval(b"$other = Object.new").expect("eval");
+        assert!(map.is_empty());
+        assert_eq!(map.insert_for_object(&interp, &listener, "callback"), None);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get_for_object(&interp, &listener), Some(&"callback"));
+        assert_eq!(map.get_for_object(&interp, &other), None);
+        assert_eq!(
+            map.insert_for_object(&interp, &listener, "replaced"),
+            Some("callback")
+        );
+        assert_eq!(
+            map.remove_for_object(&interp, &listener),
+            Some("replaced")
+        );
+        assert!(map.is_empty());
+    }
+}
@@ -1,20 +1,33 @@
 use std::any::{Any, TypeId};
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fmt;
 use std::io::{self, Write};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::class;
 use crate::eval::Context;
 use crate::fs::Filesystem;
 use crate::module;
 use crate::sys::{self, DescribeState};
+use crate::ArtichokeError;
+
+/// Default maximum depth of the eval [`Context`] stack, enforced by
+/// [`State::push_context_checked`].
+const DEFAULT_MAX_CONTEXT_DEPTH: usize = 500;
+
+/// Counter for assigning default interpreter names of the form `artichoke#N`.
+static NEXT_INTERPRETER_ID: AtomicUsize = AtomicUsize::new(0);
 
 // NOTE: ArtichokeState assumes that it it is stored in `mrb_state->ud` wrapped in a
 // [`Rc`] with type [`Artichoke`] as created by [`crate::interpreter`].
 pub struct State {
     pub mrb: *mut sys::mrb_state,
     pub ctx: *mut sys::mrbc_context,
+    name: String,
     classes: HashMap<TypeId, Box<class::Spec>>,
     modules: HashMap<TypeId, Box<module::Spec>>,
     pub vfs: Filesystem,
@@ -22,6 +35,14 @@ pub struct State {
     pub active_regexp_globals: usize,
     symbol_cache: HashMap<Cow<'static, [u8]>, sys::mrb_sym>,
     captured_output: Option<String>,
+    loaded_features: Vec<String>,
+    instruction_limit: Option<usize>,
+    instructions_remaining: usize,
+    gc_collections: usize,
+    closed: bool,
+    string_encodings: HashMap<crate::types::Int, crate::extn::core::string::encoding::Encoding>,
+    warning_mode: WarningMode,
+    max_context_depth: usize,
     #[cfg(feature = "artichoke-random")]
     prng: crate::extn::core::random::Random,
 }
@@ -30,10 +51,34 @@ impl State {
     /// Create a new [`State`] from a [`sys::mrb_state`] and
     /// [`sys::mrbc_context`] with an
     /// [in memory virtual filesystem](Filesystem).
+    ///
+    /// The state is assigned a default [name](State::name) of the form
+    /// `artichoke#N` from a process-wide counter. Embedders that run multiple
+    /// interpreters should prefer [`State::new_named`] so each instance is
+    /// identifiable in logs and debug output.
     pub fn new(mrb: *mut sys::mrb_state, ctx: *mut sys::mrbc_context, vfs: Filesystem) -> Self {
+        let id = NEXT_INTERPRETER_ID.fetch_add(1, Ordering::SeqCst);
+        Self::new_named(mrb, ctx, vfs, format!("artichoke#{}", id))
+    }
+
+    /// Create a new [`State`] with an embedder-supplied name.
+    ///
+    /// The name is included in the [`fmt::Debug`] and [`fmt::Display`]
+    /// implementations for `State` so interpreters can be told apart in
+    /// multi-tenant embeddings.
+    pub fn new_named<T>(
+        mrb: *mut sys::mrb_state,
+        ctx: *mut sys::mrbc_context,
+        vfs: Filesystem,
+        name: T,
+    ) -> Self
+    where
+        T: Into<String>,
+    {
         Self {
             mrb,
             ctx,
+            name: name.into(),
             classes: HashMap::default(),
             modules: HashMap::default(),
             vfs,
@@ -41,11 +86,53 @@ impl State {
             active_regexp_globals: 0,
             symbol_cache: HashMap::default(),
             captured_output: None,
+            loaded_features: vec![],
+            instruction_limit: None,
+            instructions_remaining: 0,
+            gc_collections: 0,
+            closed: false,
+            string_encodings: HashMap::default(),
+            warning_mode: WarningMode::default(),
+            max_context_depth: DEFAULT_MAX_CONTEXT_DEPTH,
             #[cfg(feature = "artichoke-random")]
             prng: crate::extn::core::random::new(None),
         }
     }
 
+    /// The name assigned to this interpreter instance.
+    ///
+    /// Names default to `artichoke#N` unless supplied with
+    /// [`State::new_named`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The [`Encoding`](crate::extn::core::string::encoding::Encoding)
+    /// associated with the `String` with the given object id.
+    ///
+    /// `String`s with no recorded encoding are UTF-8; see
+    /// `extn::core::string`.
+    pub fn string_encoding(
+        &self,
+        id: crate::types::Int,
+    ) -> Option<crate::extn::core::string::encoding::Encoding> {
+        self.string_encodings.get(&id).copied()
+    }
+
+    /// Associate an [`Encoding`](crate::extn::core::string::encoding::Encoding)
+    /// with the `String` with the given object id.
+    ///
+    /// Object ids are derived from object pointers and can be recycled by the
+    /// GC, so encodings may leak between `String`s with non-overlapping
+    /// lifetimes. This is a known limitation of the minimal encoding support.
+    pub fn set_string_encoding(
+        &mut self,
+        id: crate::types::Int,
+        encoding: crate::extn::core::string::encoding::Encoding,
+    ) {
+        self.string_encodings.insert(id, encoding);
+    }
+
     #[cfg(feature = "artichoke-random")]
     pub fn prng(&self) -> &crate::extn::core::random::Random {
         &self.prng
@@ -56,6 +143,166 @@ impl State {
         &mut self.prng
     }
 
+    /// The seed of the interpreter-global PRNG.
+    ///
+    /// The PRNG is reseeded by `Kernel#srand` and `Random.srand`, which both
+    /// replace [`State::prng`] via [`State::prng_mut`].
+    #[cfg(feature = "artichoke-random")]
+    pub fn prng_seed(&self) -> u64 {
+        self.prng.seed_value().unwrap_or_default()
+    }
+
+    /// Take a point-in-time snapshot of interpreter overhead.
+    ///
+    /// Metrics are cheap to collect: aside from the live object count, which
+    /// reads the mruby GC state, all fields come from existing [`State`]
+    /// bookkeeping.
+    pub fn metrics(&self) -> InterpreterMetrics {
+        let live_objects =
+            usize::try_from(unsafe { sys::mrb_sys_gc_live_objects(self.mrb) }).unwrap_or_default();
+        InterpreterMetrics {
+            live_objects,
+            gc_collections: self.gc_collections,
+            class_count: self.classes.len(),
+            module_count: self.modules.len(),
+            symbol_count: self.symbol_cache.len(),
+            context_depth: self.context_stack.len(),
+            nonexhaustive: (),
+        }
+    }
+
+    /// Record a garbage collection pass for [`State::metrics`].
+    ///
+    /// Called by
+    /// [`MrbGarbageCollection::full_gc`](crate::gc::MrbGarbageCollection::full_gc)
+    /// and
+    /// [`MrbGarbageCollection::incremental_gc`](crate::gc::MrbGarbageCollection::incremental_gc).
+    /// Collections triggered internally by the mruby VM are not counted.
+    pub(crate) fn record_gc_collection(&mut self) {
+        self.gc_collections += 1;
+    }
+
+    /// Set or clear the VM instruction limit.
+    ///
+    /// Setting a limit also resets the remaining instruction budget. The limit
+    /// is enforced by the code fetch hook installed by
+    /// [`Artichoke::set_instruction_limit`](crate::Artichoke::set_instruction_limit).
+    pub fn set_instruction_limit(&mut self, limit: Option<usize>) {
+        self.instruction_limit = limit;
+        self.instructions_remaining = limit.unwrap_or_default();
+    }
+
+    /// The configured VM instruction limit, if any.
+    pub fn instruction_limit(&self) -> Option<usize> {
+        self.instruction_limit
+    }
+
+    /// Reset the remaining instruction budget to the configured
+    /// [instruction limit](State::instruction_limit).
+    ///
+    /// [`Eval::eval`](artichoke_core::eval::Eval::eval) resets the budget after
+    /// each eval so repeated calls each get a fresh budget.
+    pub fn reset_instruction_budget(&mut self) {
+        self.instructions_remaining = self.instruction_limit.unwrap_or_default();
+    }
+
+    /// Consume one VM instruction from the remaining budget.
+    ///
+    /// Returns true if the budget is exhausted and execution should be
+    /// interrupted. Always returns false if no instruction limit is set.
+    pub fn consume_instruction(&mut self) -> bool {
+        if self.instruction_limit.is_none() {
+            return false;
+        }
+        if let Some(remaining) = self.instructions_remaining.checked_sub(1) {
+            self.instructions_remaining = remaining;
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Push an eval [`Context`] onto the stack, failing if the stack is at
+    /// the configured [depth limit](State::max_context_depth).
+    ///
+    /// Deeply recursive `require` chains push one context per file.
+    /// `Kernel#require` and `Kernel#load` use this checked variant so runaway
+    /// require cycles raise `SystemStackError` instead of overflowing the
+    /// host thread's OS stack.
+    pub fn push_context_checked(&mut self, context: Context) -> Result<(), ArtichokeError> {
+        if self.context_stack.len() >= self.max_context_depth {
+            return Err(ArtichokeError::StackLevelTooDeep {
+                max: self.max_context_depth,
+            });
+        }
+        self.context_stack.push(context);
+        Ok(())
+    }
+
+    /// The maximum depth of the eval [`Context`] stack enforced by
+    /// [`State::push_context_checked`]. Defaults to 500.
+    pub fn max_context_depth(&self) -> usize {
+        self.max_context_depth
+    }
+
+    /// Set the maximum depth of the eval [`Context`] stack.
+    ///
+    /// Embedders with deeply nested load paths or small thread stacks can
+    /// tune the limit.
+    pub fn set_max_context_depth(&mut self, max: usize) {
+        self.max_context_depth = max;
+    }
+
+    /// Save the current GC arena index.
+    ///
+    /// mruby tracks intermediate objects created via the C API in the
+    /// [arena](https://github.com/mruby/mruby/blob/master/doc/guides/gc-arena-howto.md).
+    /// Extension methods that allocate many intermediate `mrb_value`s should
+    /// save the arena index on entry and restore it before returning so the
+    /// intermediates become eligible for collection. Prefer holding a
+    /// [`GcArenaGuard`] which restores the saved index on drop.
+    pub fn gc_arena_save(&self) -> i32 {
+        unsafe { sys::mrb_sys_gc_arena_save(self.mrb) }
+    }
+
+    /// Restore the GC arena to a previously [saved](State::gc_arena_save)
+    /// index.
+    ///
+    /// All objects placed in the arena after the save become unreachable from
+    /// the arena and may be reaped by the next GC.
+    pub fn gc_arena_restore(&self, idx: i32) {
+        unsafe { sys::mrb_sys_gc_arena_restore(self.mrb, idx) };
+    }
+
+    /// Retrieve the set of loaded features, analogous to `$LOADED_FEATURES`
+    /// (a.k.a. `$"`) in MRI.
+    ///
+    /// Features are returned in load order. Features are added to this set by
+    /// [`State::mark_loaded`], which
+    /// [`Kernel#require`](crate::extn::core::kernel::Kernel::require) and
+    /// [`Kernel#require_relative`](crate::extn::core::kernel::Kernel::require_relative)
+    /// call on successful loads.
+    pub fn loaded_features(&self) -> Vec<String> {
+        self.loaded_features.clone()
+    }
+
+    /// Add a path to the set of [loaded features](State::loaded_features).
+    ///
+    /// Marking a path as loaded causes subsequent `require`s of that path to
+    /// short circuit, which allows embedders to pre-populate the
+    /// loaded-features set to skip redundant loads.
+    pub fn mark_loaded(&mut self, path: &str) {
+        if !self.is_loaded(path) {
+            self.loaded_features.push(String::from(path));
+        }
+    }
+
+    /// Whether a path is in the set of
+    /// [loaded features](State::loaded_features).
+    pub fn is_loaded(&self, path: &str) -> bool {
+        self.loaded_features.iter().any(|feature| feature == path)
+    }
+
     pub fn capture_output(&mut self) {
         self.captured_output = Some(String::default());
     }
@@ -66,6 +313,26 @@ impl State {
             .unwrap_or_default()
     }
 
+    /// Borrow the captured output without consuming it.
+    ///
+    /// Returns [`None`] if output capturing is not enabled with
+    /// [`State::capture_output`]. Successive calls return the same content
+    /// until the buffer is cleared with [`State::clear_captured_output`] or
+    /// consumed with [`State::get_and_clear_captured_output`].
+    pub fn peek_captured_output(&self) -> Option<&str> {
+        self.captured_output.as_ref().map(String::as_str)
+    }
+
+    /// Discard the captured output without returning it.
+    ///
+    /// Capturing remains enabled; subsequent writes accumulate in an empty
+    /// buffer.
+    pub fn clear_captured_output(&mut self) {
+        if let Some(ref mut captured_output) = self.captured_output {
+            captured_output.clear();
+        }
+    }
+
     pub fn print(&mut self, s: &str) {
         if let Some(ref mut captured_output) = self.captured_output {
             captured_output.push_str(s);
@@ -84,8 +351,50 @@ impl State {
         }
     }
 
+    /// Write a line to the interpreter's error stream.
+    ///
+    /// Like [`State::puts`], output is redirected to the captured output
+    /// buffer when capturing is enabled with [`State::capture_output`], which
+    /// lets tests assert on warning content.
+    pub fn eputs(&mut self, s: &str) {
+        if let Some(ref mut captured_output) = self.captured_output {
+            captured_output.push_str(s);
+            captured_output.push('\n');
+        } else {
+            eprintln!("{}", s);
+        }
+    }
+
+    /// The configured [`WarningMode`].
+    ///
+    /// [`Warn::warn`](artichoke_core::warn::Warn::warn) consults the mode
+    /// before emitting.
+    pub fn warning_mode(&self) -> WarningMode {
+        self.warning_mode
+    }
+
+    /// Set the [`WarningMode`], analogous to the `-W` command line flag in
+    /// MRI.
+    pub fn set_warning_mode(&mut self, mode: WarningMode) {
+        self.warning_mode = mode;
+    }
+
+    /// Whether this [`State`] has been [closed](State::close).
+    ///
+    /// Using a closed state is a use-after-free: the underlying
+    /// [`sys::mrb_state`] is freed and [`State::mrb`] is null. Entry points
+    /// that run code on the VM check this flag and return
+    /// [`ArtichokeError::InterpreterClosed`](crate::ArtichokeError::InterpreterClosed)
+    /// instead of dereferencing the dangling pointer.
+    pub fn is_closed(&self) -> bool {
+        self.closed || self.mrb.is_null()
+    }
+
     /// Close a [`State`] and free underlying mruby structs and memory.
+    ///
+    /// Closing is idempotent: a second call is a no-op.
     pub fn close(&mut self) {
+        self.closed = true;
         unsafe {
             // At this point, the only refs to the smart poitner wrapping the
             // state are stored in the `mrb_state->ud` pointer and any
@@ -94,14 +403,15 @@ impl State {
             // To clean up:
             //
             // - Save the raw pointer to the `Artichoke` from the user data.
+            // - Clear the userdata pointer so `MRB_TT_DATA` free functions
+            //   that run during `mrb_close` cannot resurrect a strong ref to
+            //   the state through [`ffi::from_user_data`]. Destructors that
+            //   need the interpreter should hold a `WeakArtichoke`.
             // - Free the mrb context.
-            // - Close the interpreter which frees every object in the heap and
-            //   drops the strong count on the Rc to 1.
-            // - Rematerialize the `Rc`.
-            // - Drop the `Rc` which drops the strong count to 0 and frees the
-            //   state.
-            // - Set the userdata pointer to null.
+            // - Close the interpreter which frees every object in the heap.
             // - Set context and mrb properties to null.
+            // - Rematerialize the `Rc` saved in the user data and drop it,
+            //   which releases the strong count the `mrb_state` held.
             if self.mrb.is_null() {
                 return;
             }
@@ -109,12 +419,14 @@ impl State {
             if ptr.is_null() {
                 return;
             }
+            (*self.mrb).ud = std::ptr::null_mut();
             // Free mrb data structures
             sys::mrbc_context_free(self.mrb, self.ctx);
             sys::mrb_close(self.mrb);
             // Cleanup dangling pointers
             self.ctx = std::ptr::null_mut();
             self.mrb = std::ptr::null_mut();
+            drop(Rc::from_raw(ptr as *const RefCell<Self>));
         };
     }
 
@@ -177,14 +489,294 @@ impl State {
     }
 }
 
+/// Warning verbosity, mirroring the `-W` command line flag in MRI.
+///
+/// The mode is stored on the [`State`] and consulted by
+/// [`Warn::warn`](artichoke_core::warn::Warn::warn) before emitting a warning
+/// with [`State::eputs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningMode {
+    /// Emit all warnings, analogous to `-W2` / `$VERBOSE = true`.
+    Verbose,
+    /// Emit warnings, analogous to `-W1` / `$VERBOSE = false`. This is the
+    /// default.
+    Normal,
+    /// Suppress all warnings, analogous to `-W0` / `$VERBOSE = nil`.
+    Quiet,
+}
+
+impl Default for WarningMode {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// Point-in-time snapshot of interpreter overhead, returned by
+/// [`State::metrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InterpreterMetrics {
+    /// Number of live objects on the interpreter heap.
+    pub live_objects: usize,
+    /// Number of garbage collection passes triggered through the Rust API.
+    pub gc_collections: usize,
+    /// Number of class specs registered with [`State::def_class`].
+    pub class_count: usize,
+    /// Number of module specs registered with [`State::def_module`].
+    pub module_count: usize,
+    /// Number of symbols interned with [`State::sym_intern`].
+    pub symbol_count: usize,
+    /// Depth of the eval [`Context`] stack.
+    pub context_depth: usize,
+    // Prevent exhaustive construction and destructuring so fields can be added
+    // without breaking embedders. `#[non_exhaustive]` requires a newer
+    // toolchain than the one this crate pins.
+    nonexhaustive: (),
+}
+
+impl InterpreterMetrics {
+    /// Field-wise difference between `self` and an earlier snapshot.
+    ///
+    /// Enables before/after profiling of a block of work:
+    ///
+    /// ```rust
+    /// let interp = artichoke_backend::interpreter().unwrap();
+    /// let before = interp.0.borrow().metrics();
+    /// // ... eval some code ...
+    /// let delta = interp.0.borrow().metrics().diff(&before);
+    /// assert_eq!(delta.context_depth, 0);
+    /// ```
+    pub fn diff(&self, earlier: &Self) -> MetricsDelta {
+        MetricsDelta {
+            live_objects: delta(self.live_objects, earlier.live_objects),
+            gc_collections: delta(self.gc_collections, earlier.gc_collections),
+            class_count: delta(self.class_count, earlier.class_count),
+            module_count: delta(self.module_count, earlier.module_count),
+            symbol_count: delta(self.symbol_count, earlier.symbol_count),
+            context_depth: delta(self.context_depth, earlier.context_depth),
+            nonexhaustive: (),
+        }
+    }
+}
+
+/// Field-wise deltas between two [`InterpreterMetrics`] snapshots, returned by
+/// [`InterpreterMetrics::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetricsDelta {
+    /// Change in live object count.
+    pub live_objects: isize,
+    /// Number of garbage collection passes between the snapshots.
+    pub gc_collections: isize,
+    /// Change in registered class spec count.
+    pub class_count: isize,
+    /// Change in registered module spec count.
+    pub module_count: isize,
+    /// Change in interned symbol count.
+    pub symbol_count: isize,
+    /// Change in eval context stack depth.
+    pub context_depth: isize,
+    // See `InterpreterMetrics::nonexhaustive`.
+    nonexhaustive: (),
+}
+
+fn delta(current: usize, earlier: usize) -> isize {
+    if current >= earlier {
+        isize::try_from(current - earlier).unwrap_or_else(|_| isize::max_value())
+    } else {
+        -isize::try_from(earlier - current).unwrap_or_else(|_| isize::max_value())
+    }
+}
+
+/// RAII guard around a saved GC arena index.
+///
+/// A `GcArenaGuard` calls [`State::gc_arena_save`] on creation and
+/// [`State::gc_arena_restore`] on drop. Extension methods registered with
+/// [`class::Builder::add_method`](crate::class::Builder::add_method) that
+/// perform long allocating operations should hold a `GcArenaGuard` for the
+/// duration of the operation to keep the arena from growing without bound.
+///
+/// Unlike [`ArenaIndex`](crate::gc::ArenaIndex), a `GcArenaGuard` borrows the
+/// [`State`] directly, so it can be used from contexts that already hold a
+/// borrow of the interpreter `RefCell`.
+#[derive(Debug)]
+pub struct GcArenaGuard<'a> {
+    state: &'a State,
+    index: i32,
+}
+
+impl<'a> GcArenaGuard<'a> {
+    /// Save the current arena index and construct a guard that restores it on
+    /// drop.
+    pub fn new(state: &'a State) -> Self {
+        let index = state.gc_arena_save();
+        Self { state, index }
+    }
+
+    /// Restore the arena stack pointer to the index saved at construction.
+    pub fn restore(self) {
+        drop(self);
+    }
+}
+
+impl<'a> Drop for GcArenaGuard<'a> {
+    fn drop(&mut self) {
+        self.state.gc_arena_restore(self.index);
+    }
+}
+
 impl fmt::Debug for State {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.mrb.debug())
+        write!(f, "{} ({})", self.name, self.mrb.debug())
     }
 }
 
 impl fmt::Display for State {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.mrb.info())
+        write!(f, "{} ({})", self.name, self.mrb.info())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::load::LoadSources;
+    use artichoke_core::value::Value as _;
+
+    use crate::gc::MrbGarbageCollection;
+    use crate::state::GcArenaGuard;
+    use crate::sys;
+    use crate::value::ValueLike;
+
+    #[test]
+    fn loaded_features_track_requires() {
+        let interp = crate::interpreter().expect("init");
+        interp
+            .def_rb_source_file(b"foo.rb", &b"# a source file"[..])
+            .expect("def file");
+        assert!(interp.0.borrow().loaded_features().is_empty());
+        let result = interp.eval(b"require 'foo'").expect("eval");
+        assert!(result.try_into::<bool>().expect("convert"));
+        assert_eq!(
+            interp.0.borrow().loaded_features(),
+            vec![String::from("/src/lib/foo.rb")]
+        );
+        // Re-requiring a loaded feature does not duplicate it.
+        let result = interp.eval(b"require 'foo'").expect("eval");
+        assert!(!result.try_into::<bool>().expect("convert"));
+        assert_eq!(interp.0.borrow().loaded_features().len(), 1);
+    }
+
+    #[test]
+    fn mark_loaded_skips_redundant_require() {
+        let interp = crate::interpreter().expect("init");
+        interp
+            .def_rb_source_file(b"foo.rb", &b"raise 'should not be loaded'"[..])
+            .expect("def file");
+        interp.0.borrow_mut().mark_loaded("/src/lib/foo.rb");
+        let result = interp.eval(b"require 'foo'").expect("eval");
+        assert!(
+            !result.try_into::<bool>().expect("convert"),
+            "pre-populated loaded features short circuit require"
+        );
+    }
+
+    #[test]
+    fn push_context_checked_enforces_depth_limit() {
+        let interp = crate::interpreter().expect("init");
+        interp.0.borrow_mut().set_max_context_depth(2);
+        let mut borrow = interp.0.borrow_mut();
+        assert_eq!(borrow.max_context_depth(), 2);
+        borrow
+            .push_context_checked(crate::eval::Context::root())
+            .expect("push");
+        borrow
+            .push_context_checked(crate::eval::Context::root())
+            .expect("push");
+        let err = borrow
+            .push_context_checked(crate::eval::Context::root())
+            .unwrap_err();
+        assert_eq!(err, crate::ArtichokeError::StackLevelTooDeep { max: 2 });
+        assert_eq!(borrow.context_stack.len(), 2);
+    }
+
+    #[test]
+    fn gc_arena_save_restore() {
+        let interp = crate::interpreter().expect("init");
+        let baseline_object_count = interp.live_object_count();
+        let idx = interp.0.borrow().gc_arena_save();
+        for _ in 0..2000 {
+            let value = interp.eval(b"'a'").expect("value");
+            let _ = value.to_s();
+        }
+        interp.0.borrow().gc_arena_restore(idx);
+        interp.full_gc();
+        assert_eq!(
+            interp.live_object_count(),
+            // plus 1 because stack keep is enabled in eval which marks the last
+            // returned value as live.
+            baseline_object_count + 1,
+            "Arena restore + full GC should free unreachable objects",
+        );
+    }
+
+    #[test]
+    fn gc_arena_guard_restore_on_drop() {
+        let interp = crate::interpreter().expect("init");
+        let baseline_object_count = interp.live_object_count();
+        for _ in 0..2000 {
+            let borrow = interp.0.borrow();
+            let _guard = GcArenaGuard::new(&borrow);
+            let value = unsafe { sys::mrb_str_new_cstr(borrow.mrb, b"a\0".as_ptr() as *const i8) };
+            let _ = value;
+        }
+        interp.full_gc();
+        assert_eq!(
+            interp.live_object_count(),
+            baseline_object_count,
+            "GcArenaGuard drop + full GC should free unreachable objects",
+        );
+    }
+
+    #[test]
+    fn peek_captured_output_is_non_destructive() {
+        let interp = crate::interpreter().expect("init");
+        assert_eq!(interp.0.borrow().peek_captured_output(), None);
+        interp.0.borrow_mut().capture_output();
+        let _ = interp.eval(b"puts 'hello'").expect("eval");
+        assert_eq!(interp.0.borrow().peek_captured_output(), Some("hello\n"));
+        assert_eq!(
+            interp.0.borrow().peek_captured_output(),
+            Some("hello\n"),
+            "peek does not consume the buffer"
+        );
+        interp.0.borrow_mut().clear_captured_output();
+        assert_eq!(
+            interp.0.borrow().peek_captured_output(),
+            Some(""),
+            "clear leaves capturing enabled"
+        );
+        let _ = interp.eval(b"print 'more'").expect("eval");
+        assert_eq!(
+            interp.0.borrow_mut().get_and_clear_captured_output(),
+            "more"
+        );
+    }
+
+    #[test]
+    fn metrics_snapshot_diff() {
+        let interp = crate::interpreter().expect("init");
+        let before = interp.0.borrow().metrics();
+        let _ = interp.eval(b"'a' * 1024").expect("eval");
+        interp.full_gc();
+        let delta = interp.0.borrow().metrics().diff(&before);
+        assert!(
+            delta.gc_collections >= 1,
+            "full_gc should be reflected in the collection count"
+        );
+        assert_eq!(delta.context_depth, 0, "eval should pop its context");
+        assert_eq!(delta.class_count, 0, "eval should not register class specs");
+        assert_eq!(
+            delta.module_count, 0,
+            "eval should not register module specs"
+        );
     }
 }
@@ -3,7 +3,7 @@ use std::ffi::c_void;
 use std::fmt;
 use std::mem;
 
-use crate::convert::{Convert, TryConvert};
+use crate::convert::{Convert, ConvertLossy, TryConvert};
 use crate::exception::{ExceptionHandler, LastError};
 use crate::extn::core::exception::{RubyException, TypeError};
 use crate::gc::MrbGarbageCollection;
@@ -103,18 +103,22 @@ impl Value {
     }
 
     pub fn pretty_name<'a>(&self) -> &'a str {
-        if let Ok(true) = Self::new(&self.interp, self.value).try_into::<bool>() {
-            "true"
-        } else if let Ok(false) = Self::new(&self.interp, self.value).try_into::<bool>() {
-            "false"
-        } else if let Ok(None) = Self::new(&self.interp, self.value).try_into::<Option<Self>>() {
-            "nil"
-        } else if let Ruby::Data | Ruby::Object = self.ruby_type() {
-            self.funcall::<Self>("class", &[], None)
+        match self.ruby_type() {
+            Ruby::Nil => "nil",
+            // `TryConvert<Value, bool>` applies truthiness semantics, so
+            // check the underlying boolean value directly.
+            Ruby::Bool => {
+                if unsafe { sys::mrb_sys_value_is_true(self.value) } {
+                    "true"
+                } else {
+                    "false"
+                }
+            }
+            Ruby::Data | Ruby::Object => self
+                .funcall::<Self>("class", &[], None)
                 .and_then(|class| class.funcall::<&'a str>("name", &[], None))
-                .unwrap_or_default()
-        } else {
-            self.ruby_type().class_name()
+                .unwrap_or_default(),
+            _ => self.ruby_type().class_name(),
         }
     }
 
@@ -209,6 +213,9 @@ impl ValueLike for Value {
         // get access to the underlying `ArtichokeState`.
         let (mrb, _ctx) = {
             let borrow = self.interp.0.borrow();
+            if borrow.is_closed() {
+                return Err(ArtichokeError::InterpreterClosed);
+            }
             (borrow.mrb, borrow.ctx)
         };
 
@@ -287,6 +294,9 @@ impl ValueLike for Value {
         // get access to the underlying `ArtichokeState`.
         let (mrb, _ctx) = {
             let borrow = self.interp.0.borrow();
+            if borrow.is_closed() {
+                return Err(ArtichokeError::InterpreterClosed);
+            }
             (borrow.mrb, borrow.ctx)
         };
 
@@ -376,14 +386,46 @@ impl ValueLike for Value {
         unsafe { sys::mrb_sys_value_is_nil(self.inner()) }
     }
 
+    fn is_integer(&self) -> bool {
+        self.ruby_type() == Ruby::Fixnum
+    }
+
+    fn is_float(&self) -> bool {
+        self.ruby_type() == Ruby::Float
+    }
+
+    fn is_string(&self) -> bool {
+        self.ruby_type() == Ruby::String
+    }
+
+    fn is_symbol(&self) -> bool {
+        self.ruby_type() == Ruby::Symbol
+    }
+
+    fn is_array(&self) -> bool {
+        self.ruby_type() == Ruby::Array
+    }
+
+    fn is_hash(&self) -> bool {
+        self.ruby_type() == Ruby::Hash
+    }
+
+    fn is_proc(&self) -> bool {
+        self.ruby_type() == Ruby::Proc
+    }
+
     fn respond_to(&self, method: &str) -> Result<bool, ArtichokeError> {
         let method = self.interp.convert(method);
         self.funcall::<bool>("respond_to?", &[method], None)
     }
 
     fn to_s(&self) -> String {
-        self.funcall::<String>("to_s", &[], None)
-            .unwrap_or_else(|_| "<unknown>".to_owned())
+        // `to_s` may return a `String` holding binary data, so extract it
+        // lossily. Values whose `to_s` raises display as "<unknown>".
+        self.funcall::<Self>("to_s", &[], None)
+            .ok()
+            .and_then(|value| self.interp.convert_lossy(value).ok())
+            .unwrap_or_else(|| "<unknown>".to_owned())
     }
 }
 
@@ -405,6 +447,84 @@ impl fmt::Debug for Value {
     }
 }
 
+/// Debug wrapper for a raw [`sys::mrb_value`].
+///
+/// [`sys::mrb_value`] is a C union, so the bindgen-derived [`fmt::Debug`]
+/// implementation prints raw bytes. `MrbValueDebug` borrows an interpreter so
+/// its formatter can read the value's type tag and contents. Immediate values
+/// include their payload:
+///
+/// ```text
+/// MrbValue::Integer(42)
+/// MrbValue::String("hello")
+/// MrbValue::Symbol(:sym)
+/// ```
+///
+/// Heap-allocated values fall back to their class name, e.g.
+/// `MrbValue::Object(StandardError)`.
+pub struct MrbValueDebug<'a>(&'a Artichoke, sys::mrb_value);
+
+impl<'a> MrbValueDebug<'a> {
+    /// Construct a new `MrbValueDebug` from an interpreter and
+    /// [`sys::mrb_value`].
+    pub fn new(interp: &'a Artichoke, value: sys::mrb_value) -> Self {
+        Self(interp, value)
+    }
+}
+
+impl<'a> fmt::Debug for MrbValueDebug<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match types::ruby_from_mrb_value(self.1) {
+            Ruby::Nil => write!(f, "MrbValue::Nil"),
+            Ruby::Bool => {
+                if unsafe { sys::mrb_sys_value_is_true(self.1) } {
+                    write!(f, "MrbValue::True")
+                } else {
+                    write!(f, "MrbValue::False")
+                }
+            }
+            Ruby::Fixnum => {
+                let int = unsafe { sys::mrb_sys_fixnum_to_cint(self.1) };
+                write!(f, "MrbValue::Integer({})", int)
+            }
+            Ruby::Float => {
+                let float = unsafe { sys::mrb_sys_float_to_cdouble(self.1) };
+                write!(f, "MrbValue::Float({})", float)
+            }
+            Ruby::Symbol => {
+                let value = Value::new(self.0, self.1);
+                write!(f, "MrbValue::Symbol(:{})", value.to_s())
+            }
+            Ruby::String => {
+                // `to_s` extracts string contents lossily, so binary strings
+                // format with U+FFFD REPLACEMENT CHARACTER.
+                let value = Value::new(self.0, self.1);
+                write!(f, "MrbValue::String({:?})", value.to_s())
+            }
+            Ruby::Data | Ruby::Object => {
+                let value = Value::new(self.0, self.1);
+                write!(f, "MrbValue::Object({})", value.pretty_name())
+            }
+            Ruby::Unreachable => write!(f, "MrbValue::Unreachable"),
+            type_tag => write!(f, "MrbValue::{:?}", type_tag),
+        }
+    }
+}
+
+impl<'a> fmt::Display for MrbValueDebug<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if types::ruby_from_mrb_value(self.1) == Ruby::Unreachable {
+            // Calling methods on unreachable values via the C API is
+            // unspecified and may result in a segfault.
+            //
+            // See: https://github.com/mruby/mruby/issues/4460
+            write!(f, "<unreachable>")
+        } else {
+            write!(f, "{}", Value::new(self.0, self.1).to_s())
+        }
+    }
+}
+
 impl Clone for Value {
     fn clone(&self) -> Self {
         Self {
@@ -437,6 +557,11 @@ impl Block {
         }
     }
 
+    /// The [`sys::mrb_value`] that this [`Block`] wraps.
+    pub fn inner(&self) -> sys::mrb_value {
+        self.value
+    }
+
     pub fn yield_arg(&self, interp: &Artichoke, arg: &Value) -> Result<Value, ArtichokeError> {
         // Ensure the borrow is out of scope by the time we eval code since
         // Rust-backed files and types may need to mutably borrow the `Artichoke` to
@@ -476,9 +601,34 @@ mod tests {
 
     use crate::convert::Convert;
     use crate::gc::MrbGarbageCollection;
-    use crate::value::{Value, ValueLike};
+    use crate::value::{MrbValueDebug, Value, ValueLike};
     use crate::ArtichokeError;
 
+    #[test]
+    fn type_tag_predicates() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"255").expect("eval");
+        assert!(value.is_integer());
+        assert!(!value.is_float());
+        let value = interp.eval(b"2.5").expect("eval");
+        assert!(value.is_float());
+        let value = interp.eval(b"'artichoke'").expect("eval");
+        assert!(value.is_string());
+        assert!(!value.is_symbol());
+        let value = interp.eval(b":artichoke").expect("eval");
+        assert!(value.is_symbol());
+        let value = interp.eval(b"[1, 2, 3]").expect("eval");
+        assert!(value.is_array());
+        assert!(!value.is_hash());
+        let value = interp.eval(b"{}").expect("eval");
+        assert!(value.is_hash());
+        let value = interp.eval(b"proc {}").expect("eval");
+        assert!(value.is_proc());
+        let value = interp.eval(b"nil").expect("eval");
+        assert!(value.is_nil());
+        assert!(!value.is_integer());
+    }
+
     #[test]
     fn to_s_true() {
         let interp = crate::interpreter().expect("init");
@@ -641,6 +791,57 @@ mod tests {
         assert_eq!(debug, r#""""#);
     }
 
+    #[test]
+    fn mrb_value_debug_formats_type_tag_and_contents() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"nil").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::Nil");
+        let value = interp.eval(b"true").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::True");
+        let value = interp.eval(b"false").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::False");
+        let value = interp.eval(b"42").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::Integer(42)");
+        let value = interp.eval(b"2.5").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::Float(2.5)");
+        let value = interp.eval(b":artichoke").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::Symbol(:artichoke)");
+        let value = interp.eval(b"'hello'").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, r#"MrbValue::String("hello")"#);
+        let value = interp.eval(b"[1, 2, 3]").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::Array");
+    }
+
+    #[test]
+    fn mrb_value_debug_falls_back_to_class_name_for_objects() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"Object.new").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::Object(Object)");
+        let value = interp.eval(b"StandardError.new").expect("eval");
+        let debug = format!("{:?}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(debug, "MrbValue::Exception");
+    }
+
+    #[test]
+    fn mrb_value_debug_display_delegates_to_to_s() {
+        let interp = crate::interpreter().expect("init");
+        let value = interp.eval(b"255").expect("eval");
+        let display = format!("{}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(display, "255");
+        let value = interp.eval(b"'hello'").expect("eval");
+        let display = format!("{}", MrbValueDebug::new(&interp, value.inner()));
+        assert_eq!(display, "hello");
+    }
+
     #[test]
     fn is_dead() {
         let interp = crate::interpreter().expect("init");
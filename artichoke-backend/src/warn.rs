@@ -1,25 +1,78 @@
-use artichoke_core::value::Value as _;
 use artichoke_core::warn::Warn;
-use std::borrow::Cow;
 
-use crate::convert::Convert;
-use crate::extn::core::warning::Warning;
-use crate::value::Value;
+use crate::state::WarningMode;
 use crate::{Artichoke, ArtichokeError};
 
 impl Warn for Artichoke {
     fn warn(&self, message: &[u8]) -> Result<(), ArtichokeError> {
         warn!("rb warning: {}", String::from_utf8_lossy(message));
-        let warning = {
-            let borrow = self.0.borrow();
-            let spec = borrow.module_spec::<Warning>().ok_or_else(|| {
-                ArtichokeError::NotDefined(Cow::Borrowed("Warn with uninitialized Warning"))
-            })?;
-            spec.value(self).ok_or_else(|| {
-                ArtichokeError::NotDefined(Cow::Borrowed("Warn with uninitialized Warning"))
-            })?
+        let mut borrow = self.0.borrow_mut();
+        if borrow.warning_mode() == WarningMode::Quiet {
+            return Ok(());
+        }
+        let message = String::from_utf8_lossy(message);
+        // `State::eputs` appends a newline, so strip a trailing newline from
+        // the message to avoid emitting a blank line.
+        let message = if message.ends_with('\n') {
+            &message[..message.len() - 1]
+        } else {
+            &message[..]
         };
-        warning.funcall::<Value>("warn", &[self.convert(message)], None)?;
+        borrow.eputs(message);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use artichoke_core::eval::Eval;
+    use artichoke_core::warn::Warn;
+
+    use crate::state::WarningMode;
+
+    #[test]
+    fn warn_emits_to_captured_output() {
+        let interp = crate::interpreter().expect("init");
+        interp.0.borrow_mut().capture_output();
+        interp.warn(b"deprecated").expect("warn");
+        assert_eq!(
+            interp.0.borrow_mut().get_and_clear_captured_output(),
+            "deprecated\n"
+        );
+        // Messages with a trailing newline are not double spaced.
+        interp.warn(b"deprecated\n").expect("warn");
+        assert_eq!(
+            interp.0.borrow_mut().get_and_clear_captured_output(),
+            "deprecated\n"
+        );
+    }
+
+    #[test]
+    fn quiet_mode_suppresses_warnings() {
+        let interp = crate::interpreter().expect("init");
+        interp.0.borrow_mut().capture_output();
+        interp.0.borrow_mut().set_warning_mode(WarningMode::Quiet);
+        interp.warn(b"deprecated").expect("warn");
+        assert_eq!(interp.0.borrow_mut().get_and_clear_captured_output(), "");
+        interp.0.borrow_mut().set_warning_mode(WarningMode::Verbose);
+        interp.warn(b"deprecated").expect("warn");
+        assert_eq!(
+            interp.0.borrow_mut().get_and_clear_captured_output(),
+            "deprecated\n"
+        );
+    }
+
+    #[test]
+    fn kernel_warn_routes_through_warn_trait() {
+        let interp = crate::interpreter().expect("init");
+        interp.0.borrow_mut().capture_output();
+        let _ = interp.eval(b"warn 'from ruby'").expect("eval");
+        assert_eq!(
+            interp.0.borrow_mut().get_and_clear_captured_output(),
+            "from ruby\n"
+        );
+        interp.0.borrow_mut().set_warning_mode(WarningMode::Quiet);
+        let _ = interp.eval(b"warn 'suppressed'").expect("eval");
+        assert_eq!(interp.0.borrow_mut().get_and_clear_captured_output(), "");
+    }
+}
@@ -28,8 +28,49 @@ pub trait Eval {
     /// Eval code on the artichoke interpreter using the current `Context`.
     ///
     /// Exceptions will unwind past this call.
+    #[deprecated(
+        since = "0.1.0",
+        note = "use `eval` or `eval_infallible`; unchecked_eval may unwind past Rust landing pads"
+    )]
     fn unchecked_eval(&self, code: &[u8]) -> Self::Value;
 
+    /// Eval code on the artichoke interpreter using the current `Context` and
+    /// panic on error.
+    ///
+    /// This is the sanctioned replacement for
+    /// [`unchecked_eval`](Eval::unchecked_eval) in code that treats an eval
+    /// failure as a fatal error: the panic is explicit and unwinds the Rust
+    /// stack, running destructors, instead of unwinding with a hidden
+    /// `longjmp` that skips them.
+    fn eval_infallible(&self, code: &[u8]) -> Self::Value {
+        match self.eval(code) {
+            Ok(value) => value,
+            Err(err) => panic!("failed to eval Ruby code: {}", err),
+        }
+    }
+
+    /// Parse code and report syntax errors without executing it.
+    ///
+    /// Returns `Ok(())` if the code parses cleanly. Implementations must not
+    /// execute any side effects of the parsed code, which makes this method
+    /// suitable for REPLs and sandboxes that validate code before running it.
+    fn check_syntax(&self, code: &[u8]) -> Result<(), ArtichokeError>;
+
+    /// Whether code is a syntactically incomplete expression that expects
+    /// more input, for example `def foo` without its matching `end`.
+    ///
+    /// REPLs use this to decide whether partial input should be buffered
+    /// until more lines arrive (a continuation prompt) or reported as a hard
+    /// syntax error. Unlike [`check_syntax`](Eval::check_syntax), only
+    /// "unexpected end-of-input" style parse failures count as incomplete.
+    ///
+    /// The default implementation considers all code complete.
+    /// Implementations with access to a parser should override it.
+    fn is_incomplete_expression(&self, code: &[u8]) -> bool {
+        let _ = code;
+        false
+    }
+
     /// Peek at the top of the [`Context`] stack.
     fn peek_context(&self) -> Option<Self::Context>;
 
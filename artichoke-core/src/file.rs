@@ -14,4 +14,29 @@ pub trait File {
     /// modules. This function is equivalent to the "init" methods of
     /// C-implemented Rubygems.
     fn require(interp: &Self::Artichoke) -> Result<(), ArtichokeError>;
+
+    /// Whether this file has already been loaded into the interpreter.
+    ///
+    /// The default implementation conservatively reports `false`, which makes
+    /// [`File::require_once`] behave like [`File::require`]. Implementations
+    /// that register observable state on the interpreter — a class or module
+    /// spec, an entry in the interpreter's loaded features — should override
+    /// this method so repeated requires are no-ops.
+    fn is_loaded(interp: &Self::Artichoke) -> bool {
+        let _ = interp;
+        false
+    }
+
+    /// Require this file at most once.
+    ///
+    /// Mirrors the idempotency contract of `Kernel#require`: returns `true`
+    /// if [`File::require`] ran and `false` if [`File::is_loaded`] reported
+    /// the file as already loaded.
+    fn require_once(interp: &Self::Artichoke) -> Result<bool, ArtichokeError> {
+        if Self::is_loaded(interp) {
+            return Ok(false);
+        }
+        Self::require(interp)?;
+        Ok(true)
+    }
 }
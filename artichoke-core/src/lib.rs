@@ -52,10 +52,58 @@ pub enum ArtichokeError {
     /// See [`Eval`](eval::Eval).
     // TODO: disabled for migration Exec(exception::Exception),
     Exec(String),
+    /// Attempted to use an interpreter that has been closed.
+    ///
+    /// Closing an interpreter frees the underlying VM. Clones of the
+    /// interpreter that outlive the close observe this error instead of
+    /// dereferencing a dangling pointer.
+    InterpreterClosed,
+    /// Invalid Ruby instance variable name.
+    ///
+    /// Instance variable names must start with `@` followed by an
+    /// identifier.
+    InvalidIvarName(String),
+    /// [`io::Error`] when performing I/O on behalf of the interpreter, for
+    /// example when interacting with the virtual filesystem.
+    Io(io::Error),
+    /// Failed to load a file because it does not exist in the virtual
+    /// filesystem.
+    ///
+    /// Ruby code observes this failure as a `LoadError` exception. The typed
+    /// variant lets embedders detect the missing-file case without parsing
+    /// exception messages.
+    LoadError {
+        /// Path of the file that could not be loaded.
+        path: String,
+    },
     /// Unable to initalize interpreter.
     New,
     /// Class or module with this name is not defined in the artichoke VM.
     NotDefined(Cow<'static, str>),
+    /// Code failed to parse during the compile step of eval.
+    ///
+    /// See [`Eval`](eval::Eval).
+    SyntaxError {
+        /// File name of the source that failed to parse.
+        file: String,
+        /// Line number on which the parser reported the error.
+        ///
+        /// Lines are one-indexed. A line of zero means the parser did not
+        /// report a line number.
+        line: usize,
+        /// Error message reported by the parser.
+        message: String,
+    },
+    /// The eval context stack exceeded the configured depth limit.
+    ///
+    /// Deeply recursive `require` chains push one context per file; bounding
+    /// the stack surfaces runaway recursion before the host thread's OS stack
+    /// overflows. Ruby code observes this failure as a `SystemStackError`
+    /// exception.
+    StackLevelTooDeep {
+        /// Configured maximum context stack depth.
+        max: usize,
+    },
     /// Arg count exceeds maximum allowed by the VM.
     TooManyArgs {
         /// Number of arguments supplied.
@@ -69,10 +117,14 @@ pub enum ArtichokeError {
     UninitializedValue(&'static str),
     /// Eval or funcall returned an interpreter-internal value.
     UnreachableValue,
-    /// [`io::Error`] when interacting with virtual filesystem.
+    /// Hidden variant that forces downstream `match`es to include a wildcard
+    /// arm.
     ///
-    /// See `artichoke_vfs`.
-    Vfs(io::Error),
+    /// `ArtichokeError` will grow variants over time. Never match on this
+    /// variant; it will be replaced with `#[non_exhaustive]` once the
+    /// attribute is available on the minimum supported Rust.
+    #[doc(hidden)]
+    __Nonexhaustive,
 }
 
 impl Eq for ArtichokeError {}
@@ -95,8 +147,28 @@ impl fmt::Display for ArtichokeError {
                 write!(f, "Failed to convert from {} to {}", from, to)
             }
             Self::Exec(backtrace) => write!(f, "{}", backtrace),
+            Self::InterpreterClosed => write!(f, "Interpreter has been closed"),
+            Self::InvalidIvarName(name) => write!(
+                f,
+                "`{}' is not allowed as an instance variable name",
+                name
+            ),
+            Self::Io(err) => write!(f, "io error: {}", err),
+            Self::LoadError { path } => {
+                write!(f, "LoadError: cannot load such file -- {}", path)
+            }
             Self::New => write!(f, "Failed to create interpreter"),
             Self::NotDefined(fqname) => write!(f, "{} not defined", fqname),
+            Self::SyntaxError {
+                file,
+                line,
+                message,
+            } => write!(f, "SyntaxError in {} at line {}: {}", file, line, message),
+            Self::StackLevelTooDeep { max } => write!(
+                f,
+                "SystemStackError: stack level too deep (max context depth {})",
+                max
+            ),
             Self::TooManyArgs { given, max } => write!(
                 f,
                 "Too many args for funcall. Gave {}, but max is {}",
@@ -109,11 +181,45 @@ impl fmt::Display for ArtichokeError {
                 class
             ),
             Self::UnreachableValue => write!(f, "Extracted unreachable type from interpreter"),
-            Self::Vfs(err) => write!(f, "mrb vfs io error: {}", err),
+            Self::__Nonexhaustive => unreachable!("__Nonexhaustive is never constructed"),
         }
     }
 }
 
+impl From<io::Error> for ArtichokeError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ArtichokeError> for io::Error {
+    fn from(err: ArtichokeError) -> Self {
+        let kind = match &err {
+            ArtichokeError::Io(inner) => inner.kind(),
+            ArtichokeError::LoadError { .. } | ArtichokeError::NotDefined(_) => {
+                io::ErrorKind::NotFound
+            }
+            ArtichokeError::ConvertToRuby { .. }
+            | ArtichokeError::ConvertToRust { .. }
+            | ArtichokeError::SyntaxError { .. } => io::ErrorKind::InvalidData,
+            ArtichokeError::InvalidIvarName(_) | ArtichokeError::TooManyArgs { .. } => {
+                io::ErrorKind::InvalidInput
+            }
+            ArtichokeError::Exec(_)
+            | ArtichokeError::InterpreterClosed
+            | ArtichokeError::New
+            | ArtichokeError::StackLevelTooDeep { .. }
+            | ArtichokeError::Uninitialized
+            | ArtichokeError::UninitializedValue(_)
+            | ArtichokeError::UnreachableValue => io::ErrorKind::Other,
+            ArtichokeError::__Nonexhaustive => {
+                unreachable!("__Nonexhaustive is never constructed")
+            }
+        };
+        Self::new(kind, err.to_string())
+    }
+}
+
 impl error::Error for ArtichokeError {
     fn description(&self) -> &str {
         "Artichoke interpreter error"
@@ -121,7 +227,7 @@ impl error::Error for ArtichokeError {
 
     fn cause(&self) -> Option<&dyn error::Error> {
         match self {
-            Self::Vfs(inner) => Some(inner),
+            Self::Io(inner) => Some(inner),
             _ => None,
         }
     }
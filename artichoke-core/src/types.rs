@@ -38,7 +38,7 @@ impl fmt::Display for Rust {
 }
 
 /// Classes of Ruby types.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Ruby {
     /// Ruby `Array` type.
     Array,
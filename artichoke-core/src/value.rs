@@ -59,6 +59,62 @@ where
     /// Whether `self` is `nil`
     fn is_nil(&self) -> bool;
 
+    /// Whether `self` is a Ruby `Integer`.
+    ///
+    /// Implementations should override this default to read the type tag of
+    /// the underlying boxed value.
+    fn is_integer(&self) -> bool {
+        false
+    }
+
+    /// Whether `self` is a Ruby `Float`.
+    ///
+    /// Implementations should override this default to read the type tag of
+    /// the underlying boxed value.
+    fn is_float(&self) -> bool {
+        false
+    }
+
+    /// Whether `self` is a Ruby `String`.
+    ///
+    /// Implementations should override this default to read the type tag of
+    /// the underlying boxed value.
+    fn is_string(&self) -> bool {
+        false
+    }
+
+    /// Whether `self` is a Ruby `Symbol`.
+    ///
+    /// Implementations should override this default to read the type tag of
+    /// the underlying boxed value.
+    fn is_symbol(&self) -> bool {
+        false
+    }
+
+    /// Whether `self` is a Ruby `Array`.
+    ///
+    /// Implementations should override this default to read the type tag of
+    /// the underlying boxed value.
+    fn is_array(&self) -> bool {
+        false
+    }
+
+    /// Whether `self` is a Ruby `Hash`.
+    ///
+    /// Implementations should override this default to read the type tag of
+    /// the underlying boxed value.
+    fn is_hash(&self) -> bool {
+        false
+    }
+
+    /// Whether `self` is a Ruby `Proc`.
+    ///
+    /// Implementations should override this default to read the type tag of
+    /// the underlying boxed value.
+    fn is_proc(&self) -> bool {
+        false
+    }
+
     /// Whether `self` responds to a method.
     ///
     /// Equivalent to invoking `#respond_to?` on this [`Value`].